@@ -6,17 +6,20 @@ import argparse
 import logging
 import base64
 import zlib
-from flask import Flask, request, jsonify
+from flask import Flask, Response, has_request_context, request, jsonify
 import gymnasium as gym
 import numpy as np
 
+try:
+    import msgpack
+except ImportError:
+    msgpack = None
+
 logger = logging.getLogger("werkzeug")
 
 
 def _mapper(obj):
     ret = obj
-    if isinstance(ret, tuple):
-        ret = ret[0]
     if isinstance(ret, int):
         ret = np.array([ret])
     if isinstance(ret, np.float32):
@@ -28,25 +31,94 @@ def _compress_b64_encode(byte_arr):
     return base64.b64encode(zlib.compress(byte_arr)).decode("utf-8")
 
 
+MSGPACK_MIMETYPE = "application/msgpack"
+
+
+def _wants_msgpack():
+    # Negotiated via the Accept header, JSON being the fallback.
+    if msgpack is None or not has_request_context():
+        return False
+    return request.accept_mimetypes.best_match(["application/json", MSGPACK_MIMETYPE]) == MSGPACK_MIMETYPE
+
+
+def _encode_binary_stream(byte_arr):
+    # Raw bytes for MessagePack, base64(zlib(bytes)) for JSON.
+    if _wants_msgpack():
+        return byte_arr
+    return _compress_b64_encode(byte_arr)
+
+
+def _msgpack_default(obj):
+    if isinstance(obj, np.generic):
+        return obj.item()
+    if isinstance(obj, np.ndarray):
+        return obj.tolist()
+    raise TypeError(f"Object of type {type(obj).__name__} is not MessagePack serializable")
+
+
+def _respond(**kwargs):
+    if _wants_msgpack():
+        return Response(msgpack.packb(kwargs, default=_msgpack_default), mimetype=MSGPACK_MIMETYPE)
+    return jsonify(**kwargs)
+
+
 def _observation_to_jsonable(obs):
+    # Composite spaces (Tuple, Dict) are encoded per component, each keeping its own dtype and shape.
+    if isinstance(obs, tuple):
+        return {"type": "tuple", "items": [_observation_to_jsonable(x) for x in obs]}
+    if isinstance(obs, dict):
+        return {"type": "dict", "items": {k: _observation_to_jsonable(v) for k, v in obs.items()}}
     obs = _mapper(obs)
+    shape = list(np.shape(obs))
     if isinstance(obs, np.ndarray):
+        # Sent in its own dtype, the client converts to the element type it asks for.
         obs = obs.flatten()
-        if obs.dtype == np.float32:
-            obs = obs.astype(np.float64)
-        if obs.dtype in (np.int32, np.uint8):
-            obs = obs.astype(np.int64)
     if sys.byteorder != "little":
         byte_arr = obs.byteswap().tobytes()
     else:
         byte_arr = obs.tobytes()
     jsonable = {
         "type": f"{obs.dtype}",
-        "data": _compress_b64_encode(byte_arr),
+        "shape": shape,
+        "data": _encode_binary_stream(byte_arr),
     }
     return jsonable
 
 
+def _info_to_jsonable(info):
+    # Info dicts may carry numpy values, e.g. the action_mask of Taxi. Arrays are sent as binary streams,
+    # same as observations.
+    if isinstance(info, dict):
+        return {str(k): _info_to_jsonable(v) for k, v in info.items()}
+    if isinstance(info, (list, tuple)):
+        return [_info_to_jsonable(x) for x in info]
+    if isinstance(info, np.ndarray):
+        return _observation_to_jsonable(info)
+    if isinstance(info, np.generic):
+        return info.item()
+    return info
+
+
+def _action_to_jsonable(action):
+    if isinstance(action, tuple):
+        return [_action_to_jsonable(x) for x in action]
+    if isinstance(action, dict):
+        return {k: _action_to_jsonable(v) for k, v in action.items()}
+    if isinstance(action, (np.ndarray, np.generic)):
+        return action.tolist()
+    return action
+
+
+def _action_from_jsonable(space, action):
+    if isinstance(space, gym.spaces.Tuple):
+        return tuple(_action_from_jsonable(s, a) for s, a in zip(space.spaces, action))
+    if isinstance(space, gym.spaces.Dict):
+        return {k: _action_from_jsonable(s, action[k]) for k, s in space.spaces.items()}
+    if isinstance(action, int):
+        return action
+    return np.array(action, dtype=space.dtype)
+
+
 def _replace_inf(num):
     if np.isneginf(num):
         return np.finfo(np.float64).min
@@ -102,7 +174,7 @@ class Envs:
             jsonable = {
                 "rows": rf.shape[0],
                 "cols": rf.shape[1],
-                "data": _compress_b64_encode(rf.tobytes()),
+                "data": _encode_binary_stream(rf.tobytes()),
             }
         else:
             jsonable = rf
@@ -121,12 +193,11 @@ class Envs:
     def list_all(self):
         return {instance_id: env.spec.id for (instance_id, env) in self.envs.items()}
 
-    def reset(self, instance_id, seed):
+    def reset(self, instance_id, seed, options=None):
         env = self._lookup_env(instance_id)
         seed = int(seed) if seed is not None else None
-        obs = env.reset(seed=seed)
-        jsonable = _observation_to_jsonable(obs)
-        return jsonable
+        obs, info = env.reset(seed=seed, options=options)
+        return [_observation_to_jsonable(obs), _info_to_jsonable(info)]
 
     def get_id(self, instance_id):
         _id = self._lookup_env(instance_id).spec.id
@@ -140,17 +211,18 @@ class Envs:
 
     def step(self, instance_id, action):
         env = self._lookup_env(instance_id)
-        if isinstance(action, int):
-            nice_action = action
-        else:
-            nice_action = np.array(action)
+        nice_action = _action_from_jsonable(env.action_space, action)
         observation, reward, terminated, truncated, info = env.step(nice_action)
         obs_jsonable = _observation_to_jsonable(observation)
-        return [obs_jsonable, reward, terminated, truncated, info]
+        return [obs_jsonable, reward, terminated, truncated, _info_to_jsonable(info)]
 
     def get_action_space_contains(self, instance_id, x):
         env = self._lookup_env(instance_id)
-        return env.action_space.contains(int(x))
+        try:
+            action = _action_from_jsonable(env.action_space, json.loads(x))
+        except (ValueError, TypeError, KeyError) as e:
+            raise InvalidUsage(f"Malformed action '{x}'") from e
+        return bool(env.action_space.contains(action))
 
     def get_action_space_info(self, instance_id):
         env = self._lookup_env(instance_id)
@@ -159,21 +231,15 @@ class Envs:
     def get_action_space_sample(self, instance_id):
         env = self._lookup_env(instance_id)
         action = env.action_space.sample()
-        if isinstance(action, (list, tuple)) or ("numpy" in str(type(action))):
-            try:
-                action = action.tolist()
-            except TypeError:
-                print(type(action))
-                print("TypeError")
-        return action
+        return _action_to_jsonable(action)
 
     def get_observation_space_contains(self, instance_id, j):
         env = self._lookup_env(instance_id)
         info = self._get_space_properties(env.observation_space)
         for key, value in j.items():
             # Convert both values to json for compaibility
-            if json.dumps(info[key]) != json.dumps(value):
-                print(f'Values for "{key}" do not match. Passed "{value}", Observed "{info[key]}".')
+            if key not in info or json.dumps(info[key]) != json.dumps(value):
+                print(f'Values for "{key}" do not match. Passed "{value}", Observed "{info.get(key)}".')
                 return False
         return True
 
@@ -183,21 +249,26 @@ class Envs:
 
     def get_transitions(self, instance_id):
         env = self._lookup_env(instance_id)
-        return env.unwrapped.P
+        # Only the toy text environments have a transition model, 501 for the others.
+        P = getattr(env.unwrapped, "P", None)
+        if P is None:
+            raise InvalidUsage(f"Environment {instance_id} has no transition model", status_code=501)
+        return P
 
     def get_episode_samples(self, instance_id, seed, count):
         seed = int(seed) if seed is not None else None
         count = int(count)
         eps = []
         for _ in range(count):
-            obs = self.reset(instance_id, seed)
+            obs, _ = self.reset(instance_id, seed)
             ep = [{"s": obs, "r": 0.0}]
             eps.append(ep)
             while True:
                 a = self.get_action_space_sample(instance_id)
                 si = self.step(instance_id, a)
                 ep.append({"s": si[0], "r": si[1]})
-                if si[2]:
+                # Truncation ends the episode too, or episodes that never terminate would never end.
+                if si[2] or si[3]:
                     break
         return eps
 
@@ -214,6 +285,17 @@ class Envs:
             # also make it flat.
             info["low"] = _normalize_infs(space.low)
             info["high"] = _normalize_infs(space.high)
+        elif info["name"] == "MultiDiscrete":
+            info["shape"] = space.shape
+            info["nvec"] = space.nvec.flatten().tolist()
+            start = space.start if hasattr(space, "start") else np.zeros_like(space.nvec)
+            info["start"] = start.flatten().tolist()
+        elif info["name"] == "MultiBinary":
+            info["shape"] = space.shape
+        elif info["name"] == "Tuple":
+            info["spaces"] = [self._get_space_properties(s) for s in space.spaces]
+        elif info["name"] == "Dict":
+            info["spaces"] = {k: self._get_space_properties(s) for k, s in space.spaces.items()}
         elif info["name"] == "HighLow":
             info["num_rows"] = space.num_rows
             info["matrix"] = _normalize_infs(space.matrix)
@@ -277,7 +359,7 @@ def get_optional_param(json_, param, default):
 
 @app.errorhandler(InvalidUsage)
 def handle_invalid_usage(error):
-    response = jsonify(error.to_dict())
+    response = _respond(**error.to_dict())
     response.status_code = error.status_code
     return response
 
@@ -303,7 +385,7 @@ def env_create():
     disable_env_checker = get_optional_param(request.get_json(), "disable_env_checker", None)
     kwargs = get_optional_param(request.get_json(), "kwargs", {})
     instance_id = envs.create(env_id, max_episode_steps, auto_reset, disable_env_checker, kwargs)
-    return jsonify(instance_id=instance_id)
+    return _respond(instance_id=instance_id)
 
 
 @app.route("/v1/envs/", methods=["GET"])
@@ -317,7 +399,7 @@ def env_list_all():
         on the server
     """
     all_envs = envs.list_all()
-    return jsonify(all_envs=all_envs)
+    return _respond(all_envs=all_envs)
 
 
 @app.route("/v1/envs/<instance_id>/", methods=["GET"])
@@ -332,7 +414,7 @@ def env_get_id(instance_id):
         - env_id: the id of the environment
     """
     _id = envs.get_id(instance_id)
-    return jsonify(id=_id)
+    return _respond(id=_id)
 
 
 @app.route("/v1/envs/<instance_id>/reset/", methods=["POST"])
@@ -345,12 +427,16 @@ def env_reset(instance_id):
         - instance_id: a short identifier (such as '3c657dbc')
         for the environment instance
         - seed: set the seed for this env's random number generator(s).
+        - options: a dict of additional information to reset the env with
     Returns:
         - observation: the initial observation of the space
+        - info: a dict containing auxiliary information, e.g. action masks
     """
-    seed = get_optional_param(request.get_json(), "seed", None)
-    observation = envs.reset(instance_id, seed)
-    return jsonify(observation=observation)
+    json_ = request.get_json()
+    seed = get_optional_param(json_, "seed", None)
+    options = get_optional_param(json_, "options", None)
+    [observation, info] = envs.reset(instance_id, seed, options)
+    return _respond(observation=observation, info=info)
 
 
 @app.route("/v1/envs/<instance_id>/render/", methods=["GET"])
@@ -363,7 +449,7 @@ def env_render(instance_id):
         - render_frame: the computed render_frame.
     """
     render_frame = envs.render(instance_id)
-    return jsonify(render_frame=render_frame)
+    return _respond(render_frame=render_frame)
 
 
 @app.route("/v1/envs/<instance_id>/step/", methods=["POST"])
@@ -385,7 +471,7 @@ def env_step(instance_id):
     json_ = request.get_json()
     action = get_required_param(json_, "action")
     [obs_jsonable, reward, terminated, truncated, info] = envs.step(instance_id, action)
-    return jsonify(observation=obs_jsonable, reward=reward, terminated=terminated, truncated=truncated, info=info)
+    return _respond(observation=obs_jsonable, reward=reward, terminated=terminated, truncated=truncated, info=info)
 
 
 @app.route("/v1/envs/<instance_id>/action_space/", methods=["GET"])
@@ -403,7 +489,7 @@ def env_action_space_info(instance_id):
     space to space
     """
     info = envs.get_action_space_info(instance_id)
-    return jsonify(info=info)
+    return _respond(info=info)
 
 
 @app.route("/v1/envs/<instance_id>/action_space/sample/", methods=["GET"])
@@ -419,7 +505,7 @@ def env_action_space_sample(instance_id):
         - action: a randomly sampled element belonging to the action_space
     """
     action = envs.get_action_space_sample(instance_id)
-    return jsonify(action=action)
+    return _respond(action=action)
 
 
 @app.route("/v1/envs/<instance_id>/action_space/contains/<action>/", methods=["GET"])
@@ -430,13 +516,13 @@ def env_action_space_contains(instance_id, action):
     Parameters:
         - instance_id: a short identifier (such as '3c657dbc')
         for the environment instance
-            - x: the value to be checked as member
+        - action: the JSON encoded value to be checked as member
     Returns:
         - member: whether the value passed as parameter belongs to the action_space
     """
 
     member = envs.get_action_space_contains(instance_id, action)
-    return jsonify(member=member)
+    return _respond(member=member)
 
 
 @app.route("/v1/envs/<instance_id>/observation_space/contains/", methods=["POST"])
@@ -452,7 +538,7 @@ def env_observation_space_contains(instance_id):
     """
     j = request.get_json()
     member = envs.get_observation_space_contains(instance_id, j)
-    return jsonify(member=member)
+    return _respond(member=member)
 
 
 @app.route("/v1/envs/<instance_id>/observation_space/", methods=["GET"])
@@ -470,7 +556,7 @@ def env_observation_space_info(instance_id):
         varies from space to space
     """
     info = envs.get_observation_space_info(instance_id)
-    return jsonify(info=info)
+    return _respond(info=info)
 
 
 @app.route("/v1/envs/<instance_id>/transitions/", methods=["GET"])
@@ -483,9 +569,10 @@ def env_get_transitions(instance_id):
         for the environment instance
     Returns:
         - transition: all transitions as tuple (probability of transition, next state, reward, done)
+        501 if the environment has no transition model
     """
     probs = envs.get_transitions(instance_id)
-    return jsonify(transitions=probs)
+    return _respond(transitions=probs)
 
 
 @app.route("/v1/envs/<instance_id>/episodes/", methods=["POST"])
@@ -505,7 +592,7 @@ def env_episode_samples(instance_id):
     count = get_required_param(json_, "count")
     seed = get_optional_param(json_, "seed", None)
     episodes = envs.get_episode_samples(instance_id, seed, count)
-    return jsonify(episodes=episodes)
+    return _respond(episodes=episodes)
 
 
 @app.route("/v1/envs/<instance_id>/", methods=["DELETE"])
@@ -0,0 +1,27 @@
+extern crate gymnasium;
+
+use gymnasium::*;
+
+// NOTE: Create a few environments on the server first, e.g. via the other examples.
+
+fn main() {
+    let api_url = "http://127.0.0.1:40004";
+    let envs = Environment::<AnySpace, AnySpace>::envs(api_url).unwrap();
+
+    for (instance_id, env_id) in envs {
+        let env = Environment::<AnySpace, AnySpace>::reference(api_url, &instance_id).unwrap();
+        println!("{env_id} ({instance_id}):");
+        println!("  observation space: {:?}", env.observation_space());
+        println!("  action space: {:?}", env.action_space());
+
+        let s = env.reset(None, None).unwrap().observation;
+        println!("  initial observation: {s:?}");
+
+        let action = env.action_space_sample().unwrap();
+        let si = env.step(&action).unwrap();
+        println!(
+            "  action {action:?} -> observation {:?}, reward {}",
+            si.observation, si.reward
+        );
+    }
+}
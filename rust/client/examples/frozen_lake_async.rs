@@ -0,0 +1,53 @@
+extern crate gymnasium;
+extern crate serde_json;
+extern crate tokio;
+
+use gymnasium::common::error::*;
+use gymnasium::*;
+use serde_json::to_value;
+use tokio::task::JoinSet;
+
+const NUM_ENVS: usize = 8;
+
+async fn run_episode(env: AsyncEnvironment<DiscreteSpace, DiscreteSpace>) -> GymResult<f64> {
+    let _ = env.reset(None, None).await?;
+    let mut tot_reward = 0.;
+    loop {
+        let action = env.action_space_sample().await?;
+        let si = env.step(&action).await?;
+        tot_reward += si.reward;
+
+        if si.truncated || si.terminated {
+            break;
+        }
+    }
+    env.close().await?;
+
+    Ok(tot_reward)
+}
+
+fn main() -> GymResult<()> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+
+    rt.block_on(async {
+        let mut episodes = JoinSet::new();
+        for _ in 0..NUM_ENVS {
+            let env = AsyncEnvironment::<DiscreteSpace, DiscreteSpace>::new(
+                "http://127.0.0.1:40004",
+                "FrozenLake-v1",
+                Some(100),
+                None,
+                None,
+                &[("is_slippery", to_value(true).unwrap())],
+            )
+            .await?;
+            episodes.spawn(run_episode(env));
+        }
+
+        while let Some(ret) = episodes.join_next().await {
+            println!("Finished episode with total reward {}", ret.unwrap()?);
+        }
+
+        Ok(())
+    })
+}
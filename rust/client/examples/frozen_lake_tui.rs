@@ -5,7 +5,7 @@ use gymnasium::*;
 use serde_json::to_value;
 
 fn main() {
-    let envs = Environment::<DiscreteSpace, DiscreteSpace>::envs("http://127.0.0.1:40004");
+    let envs = Environment::<DiscreteSpace, DiscreteSpace>::envs("http://127.0.0.1:40004").unwrap();
     println!("Open environments: {:?}", envs);
     let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
         "http://127.0.0.1:40004",
@@ -19,22 +19,24 @@ fn main() {
             ("is_slippery", to_value(true).unwrap()),
             //("desc", to_value(&["SHHH", "FHHH", "FHHF", "FFFG"])?),
         ],
-    );
+    )
+    .unwrap()
+    .close_on_drop();
 
     println!("observation space:\n{:?}\n", env.observation_space());
     println!("action space:\n{:?}\n", env.action_space());
-    let transitions_0_0 = &transitions(&env)[&(14, 2)];
+    let transitions_0_0 = &transitions(&env).unwrap()[&(14, 2)];
     println!("transtion:\n{:?}\n", transitions_0_0);
 
-    env.reset(Some(2718));
+    env.reset(Some(2718), None).unwrap();
 
     for ep in 0..100 {
-        let _ = env.reset(Some(2718));
+        let _ = env.reset(Some(2718), None).unwrap();
         let mut tot_reward = 0.;
         loop {
-            let action = env.action_space_sample();
-            let state = env.step(&action);
-            let render_frame = env.render();
+            let action = env.action_space_sample().unwrap();
+            let state = env.step(&action).unwrap();
+            let render_frame = env.render().unwrap();
             print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
             println!("{}", render_frame.as_str().unwrap());
             tot_reward += state.reward;
@@ -18,6 +18,8 @@ fn main() -> ui::Result {
         None,
         &[("render_mode", to_value("rgb_array").unwrap())],
     )
+    .unwrap()
+    .close_on_drop()
     .rc();
 
     let policy = RandomEnvironmentPolicy {
@@ -28,6 +30,7 @@ fn main() -> ui::Result {
         env.client_base_url(),
         env.instance_id(),
         None,
+        None,
         Rc::new(policy),
     )
 }
@@ -0,0 +1,212 @@
+use crate::common::{defs::*, error::*, utils::*, wire_format::*};
+use crate::value_extensions::*;
+use crate::*;
+use rand::Rng;
+use serde_json::{to_value, Value};
+use std::collections::{BTreeMap, HashMap};
+
+/// Space discovered at runtime from the server's space info, for environments whose types are not
+/// known up front. E.g. Environment::<AnySpace, AnySpace>::reference on any id from Environment::envs.
+#[derive(Clone, Debug)]
+pub enum AnySpace {
+    Discrete(DiscreteSpace),
+    /// Bounds are widened to Continous, the element type of the items is decided by the dtype on the wire.
+    Box(BoxSpace<Continous>),
+    MultiDiscrete(MultiDiscreteSpace),
+    MultiBinary(MultiBinarySpace),
+    Tuple(TupleSpace),
+    Dict(DictSpace),
+    HighLow {
+        num_rows: usize,
+        matrix: Vec<Continous>,
+    },
+    /// Spaces the client does not model. Items are still decoded from the wire.
+    Other {
+        name: String,
+        info: Value,
+    },
+}
+
+/// Item of an AnySpace, nested for the composite spaces.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AnyItem {
+    Discrete(Discrete),
+    BoxDiscrete(Vec<Discrete>),
+    BoxContinous(Vec<Continous>),
+    Tuple(Vec<AnyItem>),
+    Dict(BTreeMap<String, AnyItem>),
+}
+
+impl AnySpace {
+    pub fn name(&self) -> &str {
+        match self {
+            AnySpace::Discrete(_) => "Discrete",
+            AnySpace::Box(_) => "Box",
+            AnySpace::MultiDiscrete(_) => "MultiDiscrete",
+            AnySpace::MultiBinary(_) => "MultiBinary",
+            AnySpace::Tuple(_) => "Tuple",
+            AnySpace::Dict(_) => "Dict",
+            AnySpace::HighLow { .. } => "HighLow",
+            AnySpace::Other { name, .. } => name,
+        }
+    }
+
+    pub fn as_discrete(&self) -> Option<&DiscreteSpace> {
+        match self {
+            AnySpace::Discrete(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn as_box(&self) -> Option<&BoxSpace<Continous>> {
+        match self {
+            AnySpace::Box(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl AnyItem {
+    pub fn as_discrete(&self) -> Option<Discrete> {
+        match self {
+            AnyItem::Discrete(x) => Some(*x),
+            _ => None,
+        }
+    }
+
+    pub fn as_box_discrete(&self) -> Option<&[Discrete]> {
+        match self {
+            AnyItem::BoxDiscrete(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_box_continous(&self) -> Option<&[Continous]> {
+        match self {
+            AnyItem::BoxContinous(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_tuple(&self) -> Option<&[AnyItem]> {
+        match self {
+            AnyItem::Tuple(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    pub fn as_dict(&self) -> Option<&BTreeMap<String, AnyItem>> {
+        match self {
+            AnyItem::Dict(x) => Some(x),
+            _ => None,
+        }
+    }
+
+    /// JSON representation of the item as an action.
+    pub fn to_value(&self) -> Value {
+        match self {
+            AnyItem::Discrete(x) => Value::from(*x),
+            AnyItem::BoxDiscrete(xs) => to_value(xs).unwrap(),
+            AnyItem::BoxContinous(xs) => to_value(xs).unwrap(),
+            AnyItem::Tuple(xs) => Value::Array(xs.iter().map(AnyItem::to_value).collect()),
+            AnyItem::Dict(xs) => {
+                Value::Object(xs.iter().map(|(k, x)| (k.clone(), x.to_value())).collect())
+            }
+        }
+    }
+}
+
+impl Space for AnySpace {
+    type Item = AnyItem;
+
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = &val["info"];
+        let name = field(info, "name", Value::as_str)?;
+
+        Ok(match name {
+            "Discrete" => AnySpace::Discrete(DiscreteSpace::new(val)?),
+            "Box" => AnySpace::Box(BoxSpace::new(val)?),
+            "MultiDiscrete" => AnySpace::MultiDiscrete(MultiDiscreteSpace::new(val)?),
+            "MultiBinary" => AnySpace::MultiBinary(MultiBinarySpace::new(val)?),
+            "Tuple" => AnySpace::Tuple(TupleSpace::new(val)?),
+            "Dict" => AnySpace::Dict(DictSpace::new(val)?),
+            "HighLow" => AnySpace::HighLow {
+                num_rows: field(info, "num_rows", usize::from_value)?,
+                matrix: array_from_value(&info["matrix"])?,
+            },
+            _ => AnySpace::Other {
+                name: name.to_string(),
+                info: info.clone(),
+            },
+        })
+    }
+
+    fn action(&self, val: &Value) -> GymResult<AnyItem> {
+        match self {
+            AnySpace::Discrete(s) => s.action(val).map(AnyItem::Discrete),
+            AnySpace::Tuple(s) => s.action(val).map(AnyItem::Tuple),
+            AnySpace::Dict(s) => s.action(val).map(AnyItem::Dict),
+            _ => {
+                let is_discrete = val
+                    .as_array()
+                    .is_some_and(|xs| xs.iter().all(Value::is_i64));
+                if is_discrete {
+                    array_from_value(val).map(AnyItem::BoxDiscrete)
+                } else {
+                    array_from_value(val).map(AnyItem::BoxContinous)
+                }
+            }
+        }
+    }
+
+    fn observation(&self, val: &WireValue) -> GymResult<AnyItem> {
+        match self {
+            AnySpace::Discrete(s) => return s.observation(val).map(AnyItem::Discrete),
+            AnySpace::Tuple(s) => return s.observation(val).map(AnyItem::Tuple),
+            AnySpace::Dict(s) => return s.observation(val).map(AnyItem::Dict),
+            _ => {}
+        }
+
+        let ty = field(val.value, "type", Value::as_str)?;
+        let data = &val.get("data");
+        if is_integer_dtype(ty) {
+            deserialize_binary_stream_value(ty, data).map(AnyItem::BoxDiscrete)
+        } else {
+            deserialize_binary_stream_value(ty, data).map(AnyItem::BoxContinous)
+        }
+    }
+
+    fn action_request(&self, action: &AnyItem) -> HashMap<&str, Value> {
+        HashMap::from([("action", action.to_value())])
+    }
+
+    /// NOTE: Box items are always sampled as BoxContinous as the bounds are widened.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<AnyItem> {
+        match self {
+            AnySpace::Discrete(s) => s.sample(rng).map(AnyItem::Discrete),
+            AnySpace::Box(s) => s.sample(rng).map(AnyItem::BoxContinous),
+            AnySpace::MultiDiscrete(s) => s.sample(rng).map(AnyItem::BoxDiscrete),
+            AnySpace::MultiBinary(s) => s.sample(rng).map(AnyItem::BoxDiscrete),
+            AnySpace::Tuple(s) => s.sample(rng).map(AnyItem::Tuple),
+            AnySpace::Dict(s) => s.sample(rng).map(AnyItem::Dict),
+            AnySpace::HighLow { .. } | AnySpace::Other { .. } => Err(GymError::Unsupported(
+                format!("sampling {} spaces locally", self.name()),
+            )),
+        }
+    }
+
+    fn contains(&self, item: &AnyItem) -> bool {
+        match (self, item) {
+            (AnySpace::Discrete(s), AnyItem::Discrete(x)) => s.contains(x),
+            (AnySpace::Box(s), AnyItem::BoxContinous(x)) => s.contains(x),
+            (AnySpace::Box(s), AnyItem::BoxDiscrete(x)) => {
+                s.contains(&x.iter().map(|&x| x as Continous).collect())
+            }
+            (AnySpace::MultiDiscrete(s), AnyItem::BoxDiscrete(x)) => s.contains(x),
+            (AnySpace::MultiBinary(s), AnyItem::BoxDiscrete(x)) => s.contains(x),
+            (AnySpace::Tuple(s), AnyItem::Tuple(x)) => s.contains(x),
+            (AnySpace::Dict(s), AnyItem::Dict(x)) => s.contains(x),
+            _ => false,
+        }
+    }
+}
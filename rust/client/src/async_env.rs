@@ -0,0 +1,250 @@
+use crate::common::{defs::*, error::*, wire_format::*};
+use crate::transition_model::*;
+use crate::value_extensions::*;
+use crate::*;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Non-blocking counterpart of Environment, for driving many server instances from one async runtime.
+/// Spaces and items are decoded exactly as in Environment.
+/// NOTE: Drop cannot await, so server instances must be closed explicitly via close.
+#[derive(Debug)]
+pub struct AsyncEnvironment<O: Space, A: Space> {
+    client: AsyncClient,
+    api_url: String,
+    instance_id: String,
+    obs_space: O,
+    act_space: A,
+    closed: AtomicBool,
+}
+
+impl<O: Space, A: Space> AsyncEnvironment<O, A> {
+    pub async fn envs(api_url: &str) -> GymResult<HashMap<String, String>> {
+        let client = AsyncClient::new(api_url)?;
+
+        let url = client.make_api_url("");
+        let val = client.http_get(&url).await?.value;
+
+        envs_from_value(&val)
+    }
+
+    pub async fn new(
+        api_url: &str,
+        env_name: &str,
+        max_episode_steps: Option<Discrete>,
+        auto_reset: Option<bool>,
+        disable_env_checker: Option<bool>,
+        kwargs: &[(&str, Value)],
+    ) -> GymResult<Self> {
+        let body = make_env_request(
+            env_name,
+            max_episode_steps,
+            auto_reset,
+            disable_env_checker,
+            kwargs,
+        )?;
+
+        let c = AsyncClient::new(api_url)?;
+        let base_url = c.make_api_url("");
+        let obj = c.http_post(&base_url, &body).await?.value;
+        let inst_id = field(&obj, "instance_id", Value::as_str)?;
+
+        match Self::reference(api_url, inst_id).await {
+            Ok(env) => Ok(env),
+            Err(e) => {
+                // NOTE: Refer: Environment::new.
+                let _ = c.http_delete(&c.make_api_url(&format!("{inst_id}/"))).await;
+                Err(e)
+            }
+        }
+    }
+
+    pub async fn reference(api_url: &str, instance_id: &str) -> GymResult<Self> {
+        let client = AsyncClient::new(api_url)?;
+
+        let url = client.make_api_url(&format!("{}/observation_space/", instance_id));
+        let obj = client.http_get(&url).await?.value;
+        let obs_space = O::new(&obj)?;
+
+        let url = client.make_api_url(&format!("{}/action_space/", instance_id));
+        let obj = client.http_get(&url).await?.value;
+        let act_space = A::new(&obj)?;
+
+        let env_api_url = client.make_api_url(&format!("{instance_id}/"));
+        Ok(Self {
+            client,
+            api_url: env_api_url,
+            instance_id: instance_id.to_string(),
+            obs_space,
+            act_space,
+            closed: AtomicBool::new(false),
+        })
+    }
+
+    /// Refer: Environment::close.
+    pub async fn close(&self) -> GymResult<()> {
+        if self.closed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        self.client.http_delete(&self.api_url).await?;
+        self.closed.store(true, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    pub fn client_base_url(&self) -> &str {
+        &self.client.base_url
+    }
+
+    pub async fn name(&self) -> GymResult<String> {
+        let obj = self.client.http_get(&self.api_url).await?.value;
+
+        Ok(field(&obj, "id", Value::as_str)?.to_string())
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    /// Refer: Environment::action_space.
+    pub fn action_space(&self) -> &A {
+        &self.act_space
+    }
+
+    /// Refer: Environment::observation_space.
+    pub fn observation_space(&self) -> &O {
+        &self.obs_space
+    }
+
+    pub async fn action_space_sample(&self) -> GymResult<A::Item> {
+        let url = self.make_api_url("action_space/sample/");
+        let obj = self.client.http_get(&url).await?.value;
+        self.act_space.action(&obj["action"])
+    }
+
+    pub async fn reset(
+        &self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<O>> {
+        let url = self.make_api_url("reset/");
+        let obj = self
+            .client
+            .http_post(&url, &reset_request(seed, options))
+            .await?;
+
+        ResetInfo::from_value(&self.obs_space, &obj)
+    }
+
+    pub async fn render(&self) -> GymResult<RenderFrame> {
+        let url = self.make_api_url("render/");
+        let obj = self.client.http_get(&url).await?;
+
+        RenderFrame::from_value(&obj.get("render_frame"))
+    }
+
+    pub async fn step(&self, action: &A::Item) -> GymResult<StepInfo<O>> {
+        let req = self.act_space.action_request(action);
+
+        let url = self.make_api_url("step/");
+        let obj = self.client.http_post(&url, &req).await?;
+
+        StepInfo::from_value(&self.obs_space, &obj)
+    }
+
+    fn make_api_url(&self, path: &str) -> String {
+        format!("{}{path}", self.api_url)
+    }
+}
+
+impl<O: FiniteSpace, A: FiniteSpace> AsyncEnvironment<O, A> {
+    /// Refer: gymnasium::transition_model.
+    pub async fn transition_model(&self) -> GymResult<TransitionModel> {
+        sizes(&self.obs_space, &self.act_space)?;
+
+        let url = self.make_api_url("transitions/");
+        let obj = self.client.http_get(&url).await?.value;
+        transition_model_from_value(&obj, &self.obs_space, &self.act_space)
+    }
+
+    /// Refer: gymnasium::transitions.
+    pub async fn transitions(&self) -> GymResult<Transitions> {
+        self.transition_model()
+            .await
+            .map(TransitionModel::into_transitions)
+    }
+}
+
+#[derive(Debug)]
+pub struct AsyncClient {
+    base_url: String,
+    api_url: String,
+    client: reqwest::Client,
+}
+
+impl AsyncClient {
+    pub fn new(base_url: &str) -> GymResult<Self> {
+        let (base_url, api_url) = Client::make_urls(base_url);
+
+        Ok(Self {
+            base_url,
+            api_url,
+            client: reqwest::Client::builder().build()?,
+        })
+    }
+
+    pub fn make_api_url(&self, path: &str) -> String {
+        format!("{}{}", self.api_url, path)
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    async fn http_get(&self, url: &str) -> GymResult<Body> {
+        let res = self
+            .client
+            .get(url)
+            .headers(Client::construct_common_headers())
+            .send()
+            .await?;
+        Self::parse_response(res).await
+    }
+
+    async fn http_post<T: Serialize>(&self, url: &str, body: &HashMap<&str, T>) -> GymResult<Body> {
+        let res = self
+            .client
+            .post(url)
+            .headers(Client::construct_common_headers())
+            .json(body)
+            .send()
+            .await?;
+        Self::parse_response(res).await
+    }
+
+    async fn http_delete(&self, url: &str) -> GymResult<()> {
+        let res = self
+            .client
+            .delete(url)
+            .headers(Client::construct_common_headers())
+            .send()
+            .await?;
+        let status = res.status();
+        if !status.is_success() {
+            let format = Client::wire_format(res.headers());
+            return Err(Client::http_error(status, format, &res.bytes().await?));
+        }
+
+        Ok(())
+    }
+
+    async fn parse_response(res: reqwest::Response) -> GymResult<Body> {
+        let status = res.status();
+        let format = Client::wire_format(res.headers());
+        let body = res.bytes().await?;
+        Client::parse_body(status, format, &body)
+    }
+}
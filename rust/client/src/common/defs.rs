@@ -1,4 +1,7 @@
-use serde::Deserialize;
+use super::error::GymResult;
+use super::wire_format::WireValue;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -6,10 +9,11 @@ pub type Discrete = i64;
 pub type Continous = f64;
 
 pub trait Policy<O: Space, A: Space> {
-    fn policy(&self, s: &O::Item) -> A::Item;
+    /// NOTE: Fallible, e.g. for policies sampling the action space of a server instance.
+    fn policy(&self, s: &O::Item) -> GymResult<A::Item>;
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Transition {
     pub next_state: Discrete,
     pub probability: Continous,
@@ -22,24 +26,53 @@ pub type Transitions = HashMap<(Discrete, Discrete), Vec<Transition>>;
 pub trait Space {
     type Item;
 
-    fn new(val: &Value) -> Self;
+    fn new(val: &Value) -> GymResult<Self>
+    where
+        Self: Sized;
 
-    fn action(val: &Value) -> Self::Item;
+    fn action(&self, val: &Value) -> GymResult<Self::Item>;
 
-    fn observation(val: &Value) -> Self::Item;
+    /// From an observation of a body, whose binary streams may be raw bytes. Refer: WireValue.
+    fn observation(&self, val: &WireValue) -> GymResult<Self::Item>;
 
-    fn action_request(actions: &Self::Item) -> HashMap<&str, Value>;
+    fn action_request(&self, actions: &Self::Item) -> HashMap<&str, Value>;
+
+    /// Randomly sample an element of the space, locally i.e. without a server round-trip.
+    /// NOTE: Unsupported for spaces only known by their wire description, e.g. AnySpace::Other.
+    /// Refer: https://gymnasium.farama.org/api/spaces/#gymnasium.spaces.Space.sample
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Self::Item>;
+
+    /// Refer: https://gymnasium.farama.org/api/spaces/#gymnasium.spaces.Space.contains
+    fn contains(&self, item: &Self::Item) -> bool;
 }
 
+/// State of an episode and the reward received on entering it, 0 for the initial one.
 #[derive(Clone, Debug, Deserialize)]
 pub struct EpisodeEvent<O> {
     pub s: Vec<O>,
     pub r: Continous,
 }
 
+/// Items as the flattened s of an EpisodeEvent, same as the observations of the server's episodes endpoint.
+pub trait EpisodeState<T> {
+    fn to_state(&self) -> Vec<T>;
+}
+
+impl EpisodeState<Discrete> for Discrete {
+    fn to_state(&self) -> Vec<Discrete> {
+        vec![*self]
+    }
+}
+
+impl<T: Clone> EpisodeState<T> for Vec<T> {
+    fn to_state(&self) -> Vec<T> {
+        self.clone()
+    }
+}
+
 pub trait EpisodeGenerator<O>
 where
     for<'de> O: Deserialize<'de>,
 {
-    fn generate(&self, n: usize, seed: Option<usize>) -> Vec<Vec<EpisodeEvent<O>>>;
+    fn generate(&self, n: usize, seed: Option<usize>) -> GymResult<Vec<Vec<EpisodeEvent<O>>>>;
 }
@@ -0,0 +1,76 @@
+use std::fmt;
+
+/// Errors surfaced by the gymnasium client instead of panicking.
+#[derive(Debug)]
+pub enum GymError {
+    /// The request never produced a response (connection refused, dropped, timed out...).
+    Transport(reqwest::Error),
+    /// The server answered with a non-success status, e.g. 400 from InvalidUsage.
+    Http { status: u16, message: String },
+    /// The response is not valid JSON or is missing an expected field.
+    MalformedJson(String),
+    /// The space described by the server is not the one the client asked for.
+    SpaceMismatch { expected: String, found: String },
+    /// The binary stream dtype cannot be decoded into the requested element type.
+    DtypeMismatch { expected: String, found: String },
+    /// An element of the binary stream does not fit the requested element type, e.g. 300 as u8.
+    LossyConversion {
+        dtype: String,
+        element: String,
+        value: String,
+    },
+    /// The environment does not support the operation, e.g. render without a render mode.
+    Unsupported(String),
+    /// An argument is outside of what the environment or wrapper accepts, e.g. a FrozenLake map without a start.
+    InvalidArgument(String),
+    /// Reading or writing local files, e.g. saving a render frame.
+    Io(std::io::Error),
+}
+
+pub type GymResult<T> = Result<T, GymError>;
+
+impl fmt::Display for GymError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GymError::Transport(e) => write!(f, "transport error: {e}"),
+            GymError::Http { status, message } => write!(f, "server error {status}: {message}"),
+            GymError::MalformedJson(what) => write!(f, "malformed response: {what}"),
+            GymError::SpaceMismatch { expected, found } => {
+                write!(f, "space mismatch: expected {expected}, found {found}")
+            }
+            GymError::DtypeMismatch { expected, found } => {
+                write!(f, "dtype mismatch: expected {expected}, found {found}")
+            }
+            GymError::LossyConversion {
+                dtype,
+                element,
+                value,
+            } => write!(f, "lossy conversion of {dtype} value {value} to {element}"),
+            GymError::Unsupported(what) => write!(f, "unsupported: {what}"),
+            GymError::InvalidArgument(what) => write!(f, "invalid argument: {what}"),
+            GymError::Io(e) => write!(f, "io error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for GymError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GymError::Transport(e) => Some(e),
+            GymError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for GymError {
+    fn from(e: reqwest::Error) -> Self {
+        GymError::Transport(e)
+    }
+}
+
+impl From<serde_json::Error> for GymError {
+    fn from(e: serde_json::Error) -> Self {
+        GymError::MalformedJson(e.to_string())
+    }
+}
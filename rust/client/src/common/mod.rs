@@ -1,2 +1,4 @@
 pub mod defs;
+pub mod error;
 pub mod utils;
+pub mod wire_format;
@@ -1,40 +1,114 @@
+use crate::common::{error::*, wire_format::*};
 use crate::*;
 use base64::prelude::*;
 use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+use serde_json::Value;
 use std::io::prelude::*;
-use std::mem::size_of;
 
-pub fn deserialize_binary_stream_to_bytes(data: &str) -> Vec<u8> {
-    let data = BASE64_STANDARD.decode(data).unwrap();
+pub fn deserialize_binary_stream_to_bytes(data: &str) -> GymResult<Vec<u8>> {
+    let data = BASE64_STANDARD
+        .decode(data)
+        .map_err(|e| GymError::MalformedJson(format!("invalid base64 stream: {e}")))?;
     let mut dec = ZlibDecoder::new(&data[..]);
     let mut data = Vec::new();
-    dec.read_to_end(&mut data).unwrap();
+    dec.read_to_end(&mut data)
+        .map_err(|e| GymError::MalformedJson(format!("invalid zlib stream: {e}")))?;
 
-    data
+    Ok(data)
 }
 
-pub fn deserialize_binary_stream<T: FromCustom>(ty: &str, data: &str) -> Vec<T> {
-    if std::any::type_name::<T>() != map_py_type_name_to_rust(ty) {
-        panic!("Mismatch in types. Ensure client and server have same types.")
-    }
+/// Inverse of deserialize_binary_stream_to_bytes, i.e. base64(zlib(bytes)) as sent by the server.
+pub fn serialize_bytes_to_binary_stream(data: &[u8]) -> String {
+    let mut enc = ZlibEncoder::new(Vec::new(), Compression::default());
+    // NOTE: Writing to a Vec does not fail.
+    enc.write_all(data).unwrap();
+
+    BASE64_STANDARD.encode(enc.finish().unwrap())
+}
 
-    let data = deserialize_binary_stream_to_bytes(data);
+/// Elements of the binary stream of dtype ty, converted to T. Refer: FromCustom.
+pub fn deserialize_binary_stream<T: FromCustom>(ty: &str, data: &str) -> GymResult<Vec<T>> {
+    deserialize_bytes(ty, &deserialize_binary_stream_to_bytes(data)?)
+}
 
-    if data.len() % size_of::<T>() != 0 {
-        panic!("Recieved binary stream not in multiple of expected chunks.")
+/// Same as deserialize_binary_stream, for the 'data' in either wire format. Refer: WireValue::bytes.
+pub fn deserialize_binary_stream_value<T: FromCustom>(
+    ty: &str,
+    data: &WireValue,
+) -> GymResult<Vec<T>> {
+    deserialize_bytes(ty, &data.bytes()?)
+}
+
+/// The 'data' of binary streams is base64(zlib(bytes)) in JSON, or an array of bytes in plain JSON e.g.
+/// once expanded by WireValue::to_value. Refer: WireFormat.
+pub fn binary_stream_to_bytes(data: &Value) -> GymResult<Vec<u8>> {
+    match data {
+        Value::String(s) => deserialize_binary_stream_to_bytes(s),
+        Value::Array(xs) => xs
+            .iter()
+            .map(|x| {
+                x.as_u64()
+                    .and_then(|x| u8::try_from(x).ok())
+                    .ok_or_else(|| GymError::MalformedJson(format!("invalid byte {x}")))
+            })
+            .collect(),
+        _ => Err(GymError::MalformedJson(format!(
+            "invalid binary stream {data}"
+        ))),
     }
+}
 
-    data.chunks_exact(size_of::<T>())
-        .map(|chunk| T::from_le_bytes(chunk).unwrap())
-        .collect()
+/// Elements of dtype ty, converted to T. Refer: FromCustom.
+fn deserialize_bytes<T: FromCustom>(ty: &str, data: &[u8]) -> GymResult<Vec<T>> {
+    match ty {
+        "bool" | "uint8" => decode(ty, data, u8::from_le_bytes, |x| T::from_i64(x.into())),
+        "int8" => decode(ty, data, i8::from_le_bytes, |x| T::from_i64(x.into())),
+        "uint16" => decode(ty, data, u16::from_le_bytes, |x| T::from_i64(x.into())),
+        "int16" => decode(ty, data, i16::from_le_bytes, |x| T::from_i64(x.into())),
+        "uint32" => decode(ty, data, u32::from_le_bytes, |x| T::from_i64(x.into())),
+        "int32" => decode(ty, data, i32::from_le_bytes, |x| T::from_i64(x.into())),
+        "uint64" => decode(ty, data, u64::from_le_bytes, |x| {
+            T::from_i64(x.try_into().ok()?)
+        }),
+        "int64" => decode(ty, data, i64::from_le_bytes, T::from_i64),
+        "float32" => decode(ty, data, f32::from_le_bytes, |x| T::from_f64(x.into())),
+        "float64" => decode(ty, data, f64::from_le_bytes, T::from_f64),
+        _ => Err(GymError::DtypeMismatch {
+            expected: std::any::type_name::<T>().to_string(),
+            found: ty.to_string(),
+        }),
+    }
+}
+
+/// Whether items of dtype ty are integers, e.g. to pick the element type to decode them into.
+pub fn is_integer_dtype(ty: &str) -> bool {
+    ty == "bool" || ty.starts_with("int") || ty.starts_with("uint")
 }
 
-fn map_py_type_name_to_rust(name: &str) -> &str {
-    match name {
-        "int32" => "i32",
-        "int64" => "i64",
-        "float32" => "f32",
-        "float64" => "f64",
-        _ => "unknown",
+fn decode<T, X: Copy + std::fmt::Display, const N: usize>(
+    ty: &str,
+    data: &[u8],
+    from_le_bytes: fn([u8; N]) -> X,
+    convert: impl Fn(X) -> Option<T>,
+) -> GymResult<Vec<T>> {
+    if !data.len().is_multiple_of(N) {
+        return Err(GymError::MalformedJson(format!(
+            "binary stream of {} bytes is not a multiple of {N}",
+            data.len(),
+        )));
     }
+
+    data.chunks_exact(N)
+        .map(|chunk| {
+            // NOTE: Chunks are exactly N bytes.
+            let x = from_le_bytes(chunk.try_into().unwrap());
+            convert(x).ok_or_else(|| GymError::LossyConversion {
+                dtype: ty.to_string(),
+                element: std::any::type_name::<T>().to_string(),
+                value: x.to_string(),
+            })
+        })
+        .collect()
 }
@@ -0,0 +1,352 @@
+use crate::common::{error::*, utils::*};
+use rmpv::Value as MsgValue;
+use serde_json::{Map, Number, Value};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Encoding of the bodies, negotiated via the Accept and Content-Type headers.
+/// Both carry the same documents, except for the 'data' of binary streams i.e. observations and render
+/// frames: base64(zlib(bytes)) in JSON, while raw bytes in MessagePack. JSON is the fallback, e.g. for
+/// servers unaware of MessagePack. Refer: Body.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WireFormat {
+    #[default]
+    Json,
+    MessagePack,
+}
+
+impl WireFormat {
+    /// Preferring MessagePack, while accepting JSON.
+    pub const ACCEPT: &'static str = "application/msgpack, application/json;q=0.9";
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            WireFormat::Json => "application/json",
+            WireFormat::MessagePack => "application/msgpack",
+        }
+    }
+
+    /// Any content type other than MessagePack is assumed to be JSON.
+    pub fn from_content_type(content_type: Option<&str>) -> Self {
+        let mime_type = content_type
+            .and_then(|ct| ct.split(';').next())
+            .map(str::trim);
+        match mime_type {
+            Some("application/msgpack" | "application/x-msgpack") => WireFormat::MessagePack,
+            _ => WireFormat::Json,
+        }
+    }
+
+    /// The format to answer with, i.e. MessagePack only if explicitly accepted.
+    pub fn from_accept(accept: Option<&str>) -> Self {
+        let accepts_msgpack = accept.is_some_and(|accept| {
+            accept
+                .split(',')
+                .any(|mt| Self::from_content_type(Some(mt)) == WireFormat::MessagePack)
+        });
+        if accepts_msgpack {
+            WireFormat::MessagePack
+        } else {
+            WireFormat::Json
+        }
+    }
+
+    /// NOTE: Raw bytes are kept aside, refer: Body.
+    pub fn decode(&self, body: &[u8]) -> GymResult<Body> {
+        match self {
+            WireFormat::Json => Ok(Body::from(serde_json::from_slice::<Value>(body)?)),
+            WireFormat::MessagePack => {
+                let val = rmpv::decode::read_value(&mut &body[..])
+                    .map_err(|e| GymError::MalformedJson(format!("invalid msgpack: {e}")))?;
+                let mut binaries = Binaries::new();
+                let value = from_msgpack(val, &mut String::new(), &mut binaries)?;
+                Ok(Body { value, binaries })
+            }
+        }
+    }
+
+    /// The binary streams of body as base64(zlib(bytes)) for JSON, while as is for MessagePack.
+    pub fn encode(&self, body: Body) -> Vec<u8> {
+        let Body {
+            mut value,
+            binaries,
+        } = body;
+        match self {
+            WireFormat::Json => {
+                for (pointer, bytes) in binaries {
+                    if let Some(data) = value.pointer_mut(&pointer) {
+                        *data = Value::String(serialize_bytes_to_binary_stream(&bytes));
+                    }
+                }
+                value.to_string().into_bytes()
+            }
+            WireFormat::MessagePack => {
+                let mut val = to_msgpack(&value);
+                for (pointer, bytes) in binaries {
+                    if let Some(data) = msgpack_pointer_mut(&mut val, &pointer) {
+                        *data = MsgValue::Binary(bytes);
+                    }
+                }
+                let mut body = Vec::new();
+                // NOTE: Writing to a Vec does not fail.
+                rmpv::encode::write_value(&mut body, &val).unwrap();
+                body
+            }
+        }
+    }
+}
+
+/// Raw bytes of the binary streams of a body, by the JSON pointer of the 'data' they stand for.
+pub type Binaries = BTreeMap<String, Vec<u8>>;
+
+static NO_BINARIES: Binaries = Binaries::new();
+
+/// A body as decoded or to be encoded, i.e. the document along with the raw bytes of its binary streams.
+/// These are kept aside rather than expanded into one JSON number per byte, the 'data' they stand for being
+/// null in value. NOTE: Binary streams are where the body was built with them, never inferred from key names.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Body {
+    pub value: Value,
+    pub binaries: Binaries,
+}
+
+impl Body {
+    /// The 'data' of a binary stream.
+    pub fn binary(bytes: Vec<u8>) -> Self {
+        Self {
+            value: Value::Null,
+            binaries: Binaries::from([(String::new(), bytes)]),
+        }
+    }
+
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Body)>) -> Self {
+        let mut obj = Map::new();
+        let mut binaries = Binaries::new();
+        for (k, field) in fields {
+            let k = k.into();
+            adopt(&mut binaries, &escape(&k), field.binaries);
+            obj.insert(k, field.value);
+        }
+
+        Self {
+            value: Value::Object(obj),
+            binaries,
+        }
+    }
+
+    pub fn array(items: impl IntoIterator<Item = Body>) -> Self {
+        let mut xs = vec![];
+        let mut binaries = Binaries::new();
+        for (i, item) in items.into_iter().enumerate() {
+            adopt(&mut binaries, &i.to_string(), item.binaries);
+            xs.push(item.value);
+        }
+
+        Self {
+            value: Value::Array(xs),
+            binaries,
+        }
+    }
+
+    /// Same as indexing value, i.e. null when missing.
+    pub fn get(&self, key: &str) -> WireValue<'_> {
+        self.root().get(key)
+    }
+
+    pub fn root(&self) -> WireValue<'_> {
+        WireValue {
+            value: &self.value,
+            pointer: String::new(),
+            binaries: &self.binaries,
+        }
+    }
+}
+
+impl From<Value> for Body {
+    fn from(value: Value) -> Self {
+        Self {
+            value,
+            binaries: Binaries::new(),
+        }
+    }
+}
+
+/// A value of a Body, along with the binary streams below it. Refer: Space::observation.
+#[derive(Clone, Debug)]
+pub struct WireValue<'a> {
+    pub value: &'a Value,
+    pointer: String,
+    binaries: &'a Binaries,
+}
+
+impl<'a> WireValue<'a> {
+    /// Same as indexing value, i.e. null when missing.
+    pub fn get(&self, key: &str) -> WireValue<'a> {
+        WireValue {
+            value: &self.value[key],
+            pointer: format!("{}/{}", self.pointer, escape(key)),
+            binaries: self.binaries,
+        }
+    }
+
+    pub fn at(&self, i: usize) -> WireValue<'a> {
+        WireValue {
+            value: &self.value[i],
+            pointer: format!("{}/{i}", self.pointer),
+            binaries: self.binaries,
+        }
+    }
+
+    /// Raw bytes of the 'data' of a binary stream, in either wire format. Refer: binary_stream_to_bytes.
+    pub fn bytes(&self) -> GymResult<Cow<'a, [u8]>> {
+        match self.binaries.get(&self.pointer) {
+            Some(bytes) => Ok(Cow::Borrowed(bytes)),
+            None => binary_stream_to_bytes(self.value).map(Cow::Owned),
+        }
+    }
+
+    /// Plain JSON, with the binary streams below expanded into arrays of bytes.
+    /// NOTE: Meant for the small ones, e.g. the numpy arrays of info.
+    pub fn to_value(&self) -> Value {
+        let mut val = self.value.clone();
+        let below = self
+            .binaries
+            .range(self.pointer.clone()..)
+            .take_while(|(p, _)| p.starts_with(&self.pointer))
+            .map(|(p, bytes)| (&p[self.pointer.len()..], bytes))
+            .filter(|(p, _)| p.is_empty() || p.starts_with('/'));
+        for (p, bytes) in below {
+            if let Some(data) = val.pointer_mut(p) {
+                *data = Value::from(bytes.as_slice());
+            }
+        }
+
+        val
+    }
+}
+
+/// Plain JSON, i.e. without raw bytes.
+impl<'a> From<&'a Value> for WireValue<'a> {
+    fn from(value: &'a Value) -> Self {
+        WireValue {
+            value,
+            pointer: String::new(),
+            binaries: &NO_BINARIES,
+        }
+    }
+}
+
+fn adopt(binaries: &mut Binaries, token: &str, below: Binaries) {
+    binaries.extend(below.into_iter().map(|(p, b)| (format!("/{token}{p}"), b)));
+}
+
+/// Reference token of a JSON pointer, refer: RFC 6901.
+fn escape(key: &str) -> Cow<'_, str> {
+    if key.contains(['~', '/']) {
+        Cow::Owned(key.replace('~', "~0").replace('/', "~1"))
+    } else {
+        Cow::Borrowed(key)
+    }
+}
+
+fn msgpack_pointer_mut<'a>(val: &'a mut MsgValue, pointer: &str) -> Option<&'a mut MsgValue> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|t| t.replace("~1", "/").replace("~0", "~"))
+        .try_fold(val, |val, token| match val {
+            MsgValue::Map(kvs) => kvs
+                .iter_mut()
+                .find(|(k, _)| k.as_str() == Some(&token))
+                .map(|(_, v)| v),
+            MsgValue::Array(xs) => xs.get_mut(token.parse::<usize>().ok()?),
+            _ => None,
+        })
+}
+
+fn to_msgpack(val: &Value) -> MsgValue {
+    match val {
+        Value::Null => MsgValue::Nil,
+        Value::Bool(b) => MsgValue::Boolean(*b),
+        Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(x), _) => MsgValue::from(x),
+            (_, Some(x)) => MsgValue::from(x),
+            _ => MsgValue::F64(n.as_f64().unwrap_or_default()),
+        },
+        Value::String(s) => MsgValue::from(s.as_str()),
+        Value::Array(xs) => MsgValue::Array(xs.iter().map(to_msgpack).collect()),
+        Value::Object(obj) => MsgValue::Map(
+            obj.iter()
+                .map(|(k, v)| (MsgValue::from(k.as_str()), to_msgpack(v)))
+                .collect(),
+        ),
+    }
+}
+
+/// Raw bytes are moved to binaries, by the JSON pointer of where they are, i.e. pointer.
+fn from_msgpack(val: MsgValue, pointer: &mut String, binaries: &mut Binaries) -> GymResult<Value> {
+    Ok(match val {
+        MsgValue::Nil => Value::Null,
+        MsgValue::Boolean(b) => Value::Bool(b),
+        MsgValue::Integer(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(x), _) => Value::from(x),
+            (_, Some(x)) => Value::from(x),
+            _ => unreachable!("msgpack integers are either u64 or i64"),
+        },
+        MsgValue::F32(x) => float(x.into())?,
+        MsgValue::F64(x) => float(x)?,
+        MsgValue::String(s) => Value::String(
+            s.into_str()
+                .ok_or_else(|| GymError::MalformedJson("invalid utf-8 in msgpack".to_string()))?,
+        ),
+        MsgValue::Binary(bytes) => {
+            binaries.insert(pointer.clone(), bytes);
+            Value::Null
+        }
+        MsgValue::Array(xs) => Value::Array(
+            xs.into_iter()
+                .enumerate()
+                .map(|(i, x)| below(pointer, &i.to_string(), |p| from_msgpack(x, p, binaries)))
+                .collect::<GymResult<_>>()?,
+        ),
+        MsgValue::Map(kvs) => Value::Object(
+            kvs.into_iter()
+                .map(|(k, v)| {
+                    let k = map_key(k)?;
+                    let v = below(pointer, &escape(&k), |p| from_msgpack(v, p, binaries))?;
+                    Ok((k, v))
+                })
+                .collect::<GymResult<Map<_, _>>>()?,
+        ),
+        MsgValue::Ext(ty, _) => {
+            return Err(GymError::MalformedJson(format!(
+                "unsupported msgpack extension {ty}"
+            )))
+        }
+    })
+}
+
+fn below<T>(pointer: &mut String, token: &str, f: impl FnOnce(&mut String) -> T) -> T {
+    let len = pointer.len();
+    pointer.push('/');
+    pointer.push_str(token);
+    let ret = f(pointer);
+    pointer.truncate(len);
+    ret
+}
+
+/// Same as JSON, i.e. integer keys e.g. the states of the transitions become strings.
+fn map_key(k: MsgValue) -> GymResult<String> {
+    match k {
+        MsgValue::String(s) if s.is_str() => Ok(s.into_str().unwrap_or_default()),
+        MsgValue::Integer(n) => Ok(n.to_string()),
+        k => Err(GymError::MalformedJson(format!("invalid msgpack key {k}"))),
+    }
+}
+
+/// Same as JSON, i.e. NaN and infinity are not numbers.
+fn float(x: f64) -> GymResult<Value> {
+    Number::from_f64(x)
+        .map(Value::Number)
+        .ok_or_else(|| GymError::MalformedJson(format!("invalid number {x}")))
+}
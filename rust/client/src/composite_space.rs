@@ -0,0 +1,165 @@
+use crate::common::{defs::*, error::*, wire_format::*};
+use crate::value_extensions::*;
+use crate::*;
+use rand::Rng;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Refer: https://gymnasium.farama.org/api/spaces/composite/#gymnasium.spaces.Tuple
+/// E.g. Blackjack-v1 observations.
+#[derive(Clone, Debug)]
+pub struct TupleSpace {
+    pub spaces: Vec<AnySpace>,
+}
+
+impl Space for TupleSpace {
+    type Item = Vec<AnyItem>;
+
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "Tuple")?;
+
+        Ok(Self {
+            spaces: field(info, "spaces", Value::as_array)?
+                .iter()
+                .map(|info| AnySpace::new(&sub_space(info)))
+                .collect::<GymResult<_>>()?,
+        })
+    }
+
+    fn action(&self, val: &Value) -> GymResult<Vec<AnyItem>> {
+        let xs = val
+            .as_array()
+            .filter(|xs| xs.len() == self.spaces.len())
+            .ok_or_else(|| GymError::MalformedJson(format!("invalid Tuple action {val}")))?;
+
+        self.spaces
+            .iter()
+            .zip(xs)
+            .map(|(s, x)| s.action(x))
+            .collect()
+    }
+
+    fn observation(&self, val: &WireValue) -> GymResult<Vec<AnyItem>> {
+        let items = composite_items(val, "tuple", Value::as_array)?;
+        if items.len() != self.spaces.len() {
+            return Err(GymError::MalformedJson(format!(
+                "expected {} Tuple components, got {}",
+                self.spaces.len(),
+                items.len()
+            )));
+        }
+
+        let items = val.get("items");
+        self.spaces
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s.observation(&items.at(i)))
+            .collect()
+    }
+
+    fn action_request(&self, action: &Self::Item) -> HashMap<&str, Value> {
+        let action = action.iter().map(AnyItem::to_value).collect();
+
+        HashMap::from([("action", Value::Array(action))])
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Vec<AnyItem>> {
+        self.spaces.iter().map(|s| s.sample(rng)).collect()
+    }
+
+    fn contains(&self, item: &Vec<AnyItem>) -> bool {
+        item.len() == self.spaces.len() && self.spaces.iter().zip(item).all(|(s, x)| s.contains(x))
+    }
+}
+
+/// Refer: https://gymnasium.farama.org/api/spaces/composite/#gymnasium.spaces.Dict
+/// NOTE: Components are keyed by name, their order on the wire is not relied upon.
+#[derive(Clone, Debug)]
+pub struct DictSpace {
+    pub spaces: BTreeMap<String, AnySpace>,
+}
+
+impl Space for DictSpace {
+    type Item = BTreeMap<String, AnyItem>;
+
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "Dict")?;
+
+        Ok(Self {
+            spaces: field(info, "spaces", Value::as_object)?
+                .iter()
+                .map(|(k, info)| Ok((k.clone(), AnySpace::new(&sub_space(info))?)))
+                .collect::<GymResult<_>>()?,
+        })
+    }
+
+    fn action(&self, val: &Value) -> GymResult<Self::Item> {
+        self.spaces
+            .iter()
+            .map(|(k, s)| Ok((k.clone(), s.action(&val[k])?)))
+            .collect()
+    }
+
+    fn observation(&self, val: &WireValue) -> GymResult<Self::Item> {
+        let items = composite_items(val, "dict", Value::as_object)?;
+
+        self.spaces
+            .iter()
+            .map(|(k, s)| {
+                if !items.contains_key(k) {
+                    return Err(GymError::MalformedJson(format!(
+                        "missing Dict component '{k}'"
+                    )));
+                }
+                Ok((k.clone(), s.observation(&val.get("items").get(k))?))
+            })
+            .collect()
+    }
+
+    fn action_request(&self, action: &Self::Item) -> HashMap<&str, Value> {
+        let action = action
+            .iter()
+            .map(|(k, x)| (k.clone(), x.to_value()))
+            .collect();
+
+        HashMap::from([("action", Value::Object(action))])
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Self::Item> {
+        self.spaces
+            .iter()
+            .map(|(k, s)| Ok((k.clone(), s.sample(rng)?)))
+            .collect()
+    }
+
+    fn contains(&self, item: &Self::Item) -> bool {
+        item.len() == self.spaces.len()
+            && self
+                .spaces
+                .iter()
+                .all(|(k, s)| item.get(k).is_some_and(|x| s.contains(x)))
+    }
+}
+
+/// Sub space infos are nested without the 'info' envelope of the space endpoints.
+fn sub_space(info: &Value) -> Value {
+    serde_json::json!({ "info": info })
+}
+
+/// Components of a composite observation, encoded as { type: tuple|dict, items: ... }.
+fn composite_items<'a, T>(
+    val: &WireValue<'a>,
+    ty: &str,
+    f: impl FnOnce(&'a Value) -> Option<T>,
+) -> GymResult<T> {
+    let val = val.value;
+    let found = field(val, "type", Value::as_str)?;
+    if found != ty {
+        return Err(GymError::SpaceMismatch {
+            expected: ty.to_string(),
+            found: found.to_string(),
+        });
+    }
+
+    field(val, "items", f)
+}
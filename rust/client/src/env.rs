@@ -0,0 +1,225 @@
+use crate::common::{defs::*, error::*};
+use crate::envs::NativeEnvironment;
+use crate::*;
+use serde_json::{Map, Value};
+
+/// The environment as seen by algorithms, rollouts and the ui, whether it is served (Environment) or
+/// simulated in-process (LocalEnvironment).
+/// Refer: https://gymnasium.farama.org/api/env/
+pub trait Env {
+    type O: Space;
+    type A: Space;
+
+    fn name(&self) -> GymResult<String>;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.observation_space
+    fn observation_space(&self) -> &Self::O;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.action_space
+    fn action_space(&self) -> &Self::A;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.reset
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>>;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.step
+    fn step(&mut self, action: &<Self::A as Space>::Item) -> GymResult<StepInfo<Self::O>>;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.render
+    fn render(&self) -> GymResult<RenderFrame>;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.close
+    fn close(&mut self) -> GymResult<()>;
+}
+
+impl<O: Space, A: Space> Env for Environment<O, A> {
+    type O = O;
+    type A = A;
+
+    fn name(&self) -> GymResult<String> {
+        Environment::name(self)
+    }
+
+    fn observation_space(&self) -> &O {
+        Environment::observation_space(self)
+    }
+
+    fn action_space(&self) -> &A {
+        Environment::action_space(self)
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<O>> {
+        Environment::reset(self, seed, options)
+    }
+
+    fn step(&mut self, action: &A::Item) -> GymResult<StepInfo<O>> {
+        Environment::step(self, action)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        Environment::render(self)
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        Environment::close(self)
+    }
+}
+
+/// NativeEnvironment behind the Env interface, i.e. a drop-in replacement for Environment.
+#[derive(Debug)]
+pub struct LocalEnvironment<E: NativeEnvironment> {
+    env: E,
+    name: String,
+}
+
+impl<E: NativeEnvironment> LocalEnvironment<E> {
+    /// Named after the environment's type, e.g. "CartPole". Refer: with_name.
+    pub fn new(env: E) -> Self {
+        let name = std::any::type_name::<E>();
+        let name = name.split('<').next().unwrap_or(name);
+        let name = name.rsplit("::").next().unwrap_or(name);
+
+        Self::with_name(env, name)
+    }
+
+    /// Typically the Gymnasium id, e.g. "CartPole-v1".
+    pub fn with_name(env: E, name: &str) -> Self {
+        Self {
+            env,
+            name: name.to_string(),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E: NativeEnvironment> LocalEnvironment<E>
+where
+    E::O: FiniteSpace,
+    E::A: FiniteSpace,
+{
+    /// Refer: TransitionModel::from_native.
+    pub fn transition_model(&self) -> GymResult<TransitionModel> {
+        TransitionModel::from_native(&self.env)
+    }
+}
+
+impl<E: NativeEnvironment> Env for LocalEnvironment<E> {
+    type O = E::O;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        Ok(self.name.clone())
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    /// NOTE: Options are ignored, none of the native environments takes any.
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        _options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<E::O>> {
+        Ok(self.env.reset(seed))
+    }
+
+    /// NOTE: The native environments expect the action in their action space, so it is checked here.
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<E::O>> {
+        if !self.env.action_space().contains(action) {
+            return Err(GymError::InvalidArgument(format!(
+                "action is not contained in the action space of {}",
+                self.name
+            )));
+        }
+
+        Ok(self.env.step(action))
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render().ok_or_else(|| {
+            GymError::Unsupported(format!("{} was created without a render mode", self.name))
+        })
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        Ok(())
+    }
+}
+
+/// Initial observation and the steps taken from it.
+pub type Episode<O> = (<O as Space>::Item, Vec<StepInfo<O>>);
+
+/// Run one episode of policy on env, until it terminates or is truncated.
+/// NOTE: Unbounded for environments that do neither, e.g. when created without a TimeLimit.
+/// Refer: rollout_with_limit.
+pub fn rollout<E: Env + ?Sized>(
+    env: &mut E,
+    policy: &dyn Policy<E::O, E::A>,
+    seed: Option<u64>,
+) -> GymResult<Episode<E::O>> {
+    rollout_with_limit(env, policy, seed, None)
+}
+
+/// Same as rollout, the episode being truncated after max_episode_steps steps, same as TimeLimit.
+pub fn rollout_with_limit<E: Env + ?Sized>(
+    env: &mut E,
+    policy: &dyn Policy<E::O, E::A>,
+    seed: Option<u64>,
+    max_episode_steps: Option<usize>,
+) -> GymResult<Episode<E::O>> {
+    let (ri, steps) = rollout_actions(env, policy, seed, max_episode_steps)?;
+
+    Ok((
+        ri.observation,
+        steps.into_iter().map(|(_, si)| si).collect(),
+    ))
+}
+
+/// Same as rollout_with_limit, along with the reset info and the action taken in each step.
+pub(crate) fn rollout_actions<E: Env + ?Sized>(
+    env: &mut E,
+    policy: &dyn Policy<E::O, E::A>,
+    seed: Option<u64>,
+    max_episode_steps: Option<usize>,
+) -> GymResult<(ResetInfo<E::O>, ActionSteps<E>)> {
+    let ri = env.reset(seed, None)?;
+    let mut steps: ActionSteps<E> = vec![];
+    loop {
+        let s = steps
+            .last()
+            .map_or(&ri.observation, |(_, si)| &si.observation);
+        let a = policy.policy(s)?;
+        let mut si = env.step(&a)?;
+        if max_episode_steps.is_some_and(|max| steps.len() + 1 >= max) {
+            si.truncated = true;
+        }
+        let done = si.terminated || si.truncated;
+        steps.push((a, si));
+        if done {
+            break;
+        }
+    }
+
+    Ok((ri, steps))
+}
+
+type ActionSteps<E> = Vec<(<<E as Env>::A as Space>::Item, StepInfo<<E as Env>::O>)>;
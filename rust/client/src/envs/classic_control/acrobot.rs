@@ -0,0 +1,168 @@
+use super::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+use std::f64::consts::PI;
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/acrobot/
+/// NOTE: Uses the "book" dynamics without torque noise, same as Gymnasium's defaults.
+#[derive(Debug)]
+pub struct Acrobot {
+    state: [Continous; 4],
+    rng: NpRandom,
+    obs_space: BoxSpace<Continous>,
+    act_space: DiscreteSpace,
+}
+
+impl Acrobot {
+    const DT: f64 = 0.2;
+    const LINK_LENGTH_1: f64 = 1.;
+    const LINK_MASS_1: f64 = 1.;
+    const LINK_MASS_2: f64 = 1.;
+    const LINK_COM_POS_1: f64 = 0.5;
+    const LINK_COM_POS_2: f64 = 0.5;
+    const LINK_MOI: f64 = 1.;
+    const MAX_VEL_1: f64 = 4. * PI;
+    const MAX_VEL_2: f64 = 9. * PI;
+    const AVAIL_TORQUE: [f64; 3] = [-1., 0., 1.];
+
+    pub fn new() -> Self {
+        let high = [1., 1., 1., 1., Self::MAX_VEL_1, Self::MAX_VEL_2];
+
+        Self {
+            state: [0.; 4],
+            rng: NpRandom::from_entropy(),
+            obs_space: box_space(&high.map(|x| -x), &high),
+            act_space: DiscreteSpace { n: 3 },
+        }
+    }
+
+    fn observation(&self) -> Vec<Continous> {
+        let [theta1, theta2, dtheta1, dtheta2] = self.state;
+        float32(&[
+            theta1.cos(),
+            theta1.sin(),
+            theta2.cos(),
+            theta2.sin(),
+            dtheta1,
+            dtheta2,
+        ])
+    }
+
+    fn dsdt(s: [Continous; 4], a: Continous) -> [Continous; 4] {
+        let (m1, m2) = (Self::LINK_MASS_1, Self::LINK_MASS_2);
+        let l1 = Self::LINK_LENGTH_1;
+        let (lc1, lc2) = (Self::LINK_COM_POS_1, Self::LINK_COM_POS_2);
+        let (i1, i2) = (Self::LINK_MOI, Self::LINK_MOI);
+        let g = 9.8;
+        let [theta1, theta2, dtheta1, dtheta2] = s;
+
+        let d1 = m1 * lc1.powi(2)
+            + m2 * (l1.powi(2) + lc2.powi(2) + 2. * l1 * lc2 * theta2.cos())
+            + i1
+            + i2;
+        let d2 = m2 * (lc2.powi(2) + l1 * lc2 * theta2.cos()) + i2;
+        let phi2 = m2 * lc2 * g * (theta1 + theta2 - PI / 2.).cos();
+        let phi1 = -m2 * l1 * lc2 * dtheta2.powi(2) * theta2.sin()
+            - 2. * m2 * l1 * lc2 * dtheta2 * dtheta1 * theta2.sin()
+            + (m1 * lc1 + m2 * l1) * g * (theta1 - PI / 2.).cos()
+            + phi2;
+        let ddtheta2 = (a + d2 / d1 * phi1 - m2 * l1 * lc2 * dtheta1.powi(2) * theta2.sin() - phi2)
+            / (m2 * lc2.powi(2) + i2 - d2.powi(2) / d1);
+        let ddtheta1 = -(d2 * ddtheta2 + phi1) / d1;
+
+        [dtheta1, dtheta2, ddtheta1, ddtheta2]
+    }
+
+    /// Single fourth-order Runge-Kutta step over [0, dt], same as Gymnasium's rk4.
+    fn rk4(y0: [Continous; 4], a: Continous, dt: Continous) -> [Continous; 4] {
+        let dt2 = dt / 2.;
+        let add = |y: [Continous; 4], h: Continous, k: [Continous; 4]| {
+            [0, 1, 2, 3].map(|i| y[i] + h * k[i])
+        };
+
+        let k1 = Self::dsdt(y0, a);
+        let k2 = Self::dsdt(add(y0, dt2, k1), a);
+        let k3 = Self::dsdt(add(y0, dt2, k2), a);
+        let k4 = Self::dsdt(add(y0, dt, k3), a);
+
+        [0, 1, 2, 3].map(|i| y0[i] + dt / 6. * (k1[i] + 2. * k2[i] + 2. * k3[i] + k4[i]))
+    }
+
+    fn terminal(&self) -> bool {
+        let [theta1, theta2, _, _] = self.state;
+        -theta1.cos() - (theta2 + theta1).cos() > 1.
+    }
+}
+
+impl Default for Acrobot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NativeEnvironment for Acrobot {
+    type O = BoxSpace<Continous>;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.state = [(); 4].map(|_| self.rng.uniform(-0.1, 0.1) as f32 as Continous);
+
+        ResetInfo {
+            observation: self.observation(),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<BoxSpace<Continous>> {
+        let torque = Self::AVAIL_TORQUE[*action as usize];
+
+        let [theta1, theta2, dtheta1, dtheta2] = Self::rk4(self.state, torque, Self::DT);
+        self.state = [
+            wrap(theta1, -PI, PI),
+            wrap(theta2, -PI, PI),
+            dtheta1.clamp(-Self::MAX_VEL_1, Self::MAX_VEL_1),
+            dtheta2.clamp(-Self::MAX_VEL_2, Self::MAX_VEL_2),
+        ];
+
+        let terminated = self.terminal();
+
+        StepInfo {
+            observation: self.observation(),
+            reward: if terminated { 0. } else { -1. },
+            truncated: false,
+            terminated,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
+
+fn wrap(mut x: Continous, m: Continous, big_m: Continous) -> Continous {
+    let diff = big_m - m;
+    while x > big_m {
+        x -= diff;
+    }
+    while x < m {
+        x += diff;
+    }
+
+    x
+}
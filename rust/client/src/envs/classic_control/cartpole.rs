@@ -0,0 +1,131 @@
+use super::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+use std::f64::consts::PI;
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/cart_pole/
+#[derive(Debug)]
+pub struct CartPole {
+    state: [Continous; 4],
+    steps_beyond_terminated: Option<usize>,
+    rng: NpRandom,
+    obs_space: BoxSpace<Continous>,
+    act_space: DiscreteSpace,
+}
+
+impl CartPole {
+    const GRAVITY: f64 = 9.8;
+    const MASSCART: f64 = 1.0;
+    const MASSPOLE: f64 = 0.1;
+    const LENGTH: f64 = 0.5;
+    const FORCE_MAG: f64 = 10.0;
+    const TAU: f64 = 0.02;
+    const THETA_THRESHOLD_RADIANS: f64 = 12. * 2. * PI / 360.;
+    const X_THRESHOLD: f64 = 2.4;
+
+    pub fn new() -> Self {
+        let high = [
+            Self::X_THRESHOLD * 2.,
+            f32::MAX as Continous,
+            Self::THETA_THRESHOLD_RADIANS * 2.,
+            f32::MAX as Continous,
+        ];
+        let low = high.map(|x| -x);
+
+        Self {
+            state: [0.; 4],
+            steps_beyond_terminated: None,
+            rng: NpRandom::from_entropy(),
+            obs_space: box_space(&low, &high),
+            act_space: DiscreteSpace { n: 2 },
+        }
+    }
+}
+
+impl Default for CartPole {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NativeEnvironment for CartPole {
+    type O = BoxSpace<Continous>;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.state = [(); 4].map(|_| self.rng.uniform(-0.05, 0.05));
+        self.steps_beyond_terminated = None;
+
+        ResetInfo {
+            observation: float32(&self.state),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<BoxSpace<Continous>> {
+        let total_mass = Self::MASSPOLE + Self::MASSCART;
+        let polemass_length = Self::MASSPOLE * Self::LENGTH;
+
+        let [x, x_dot, theta, theta_dot] = self.state;
+        let force = if *action == 1 {
+            Self::FORCE_MAG
+        } else {
+            -Self::FORCE_MAG
+        };
+        let (sintheta, costheta) = theta.sin_cos();
+
+        let temp = (force + polemass_length * theta_dot.powi(2) * sintheta) / total_mass;
+        let thetaacc = (Self::GRAVITY * sintheta - costheta * temp)
+            / (Self::LENGTH * (4.0 / 3.0 - Self::MASSPOLE * costheta.powi(2) / total_mass));
+        let xacc = temp - polemass_length * thetaacc * costheta / total_mass;
+
+        self.state = [
+            x + Self::TAU * x_dot,
+            x_dot + Self::TAU * xacc,
+            theta + Self::TAU * theta_dot,
+            theta_dot + Self::TAU * thetaacc,
+        ];
+        let [x, _, theta, _] = self.state;
+
+        let terminated = !(-Self::X_THRESHOLD..=Self::X_THRESHOLD).contains(&x)
+            || !(-Self::THETA_THRESHOLD_RADIANS..=Self::THETA_THRESHOLD_RADIANS).contains(&theta);
+        let reward = match (terminated, self.steps_beyond_terminated) {
+            (false, _) => 1.,
+            (true, None) => {
+                self.steps_beyond_terminated = Some(0);
+                1.
+            }
+            (true, Some(n)) => {
+                self.steps_beyond_terminated = Some(n + 1);
+                0.
+            }
+        };
+
+        StepInfo {
+            observation: float32(&self.state),
+            reward,
+            truncated: false,
+            terminated,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
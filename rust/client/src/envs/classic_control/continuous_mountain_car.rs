@@ -0,0 +1,99 @@
+use super::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/mountain_car_continuous/
+#[derive(Debug)]
+pub struct MountainCarContinuous {
+    goal_velocity: Continous,
+    state: [Continous; 2],
+    rng: NpRandom,
+    obs_space: BoxSpace<Continous>,
+    act_space: BoxSpace<Continous>,
+}
+
+impl MountainCarContinuous {
+    const MIN_ACTION: f64 = -1.0;
+    const MAX_ACTION: f64 = 1.0;
+    const MIN_POSITION: f64 = -1.2;
+    const MAX_POSITION: f64 = 0.6;
+    const MAX_SPEED: f64 = 0.07;
+    const GOAL_POSITION: f64 = 0.45;
+    const POWER: f64 = 0.0015;
+
+    /// Gymnasium's default goal_velocity is 0.
+    pub fn new(goal_velocity: Continous) -> Self {
+        Self {
+            goal_velocity,
+            state: [0.; 2],
+            rng: NpRandom::from_entropy(),
+            obs_space: box_space(
+                &[Self::MIN_POSITION, -Self::MAX_SPEED],
+                &[Self::MAX_POSITION, Self::MAX_SPEED],
+            ),
+            act_space: box_space(&[Self::MIN_ACTION], &[Self::MAX_ACTION]),
+        }
+    }
+}
+
+impl NativeEnvironment for MountainCarContinuous {
+    type O = BoxSpace<Continous>;
+    type A = BoxSpace<Continous>;
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &BoxSpace<Continous> {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.state = [self.rng.uniform(-0.6, -0.4), 0.];
+
+        ResetInfo {
+            observation: float32(&self.state),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Vec<Continous>) -> StepInfo<BoxSpace<Continous>> {
+        // The action is float32 in Gymnasium.
+        let action = action[0] as f32 as Continous;
+        let [mut position, mut velocity] = self.state;
+
+        let force = action.clamp(Self::MIN_ACTION, Self::MAX_ACTION);
+        velocity += force * Self::POWER - 0.0025 * (3. * position).cos();
+        velocity = velocity.clamp(-Self::MAX_SPEED, Self::MAX_SPEED);
+        position += velocity;
+        position = position.clamp(Self::MIN_POSITION, Self::MAX_POSITION);
+        if position == Self::MIN_POSITION && velocity < 0. {
+            velocity = 0.;
+        }
+
+        let terminated = position >= Self::GOAL_POSITION && velocity >= self.goal_velocity;
+        let reward = if terminated { 100. } else { 0. } - action.powi(2) * 0.1;
+
+        // Unlike MountainCar, the state itself is kept as float32.
+        self.state = [position, velocity].map(|x| x as f32 as Continous);
+
+        StepInfo {
+            observation: self.state.to_vec(),
+            reward,
+            truncated: false,
+            terminated,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
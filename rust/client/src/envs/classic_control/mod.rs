@@ -0,0 +1,22 @@
+use crate::common::defs::*;
+use crate::*;
+
+pub mod acrobot;
+pub mod cartpole;
+pub mod continuous_mountain_car;
+pub mod mountain_car;
+pub mod pendulum;
+
+/// Observations and bounds are float32 in Gymnasium, the state they are computed from is float64.
+pub(crate) fn float32(xs: &[Continous]) -> Vec<Continous> {
+    xs.iter().map(|&x| x as f32 as Continous).collect()
+}
+
+/// Flat float32 Box, e.g. Box(low, high, dtype=np.float32).
+pub(crate) fn box_space(low: &[Continous], high: &[Continous]) -> BoxSpace<Continous> {
+    BoxSpace {
+        shape: vec![low.len()],
+        low: float32(low),
+        high: float32(high),
+    }
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/mountain_car/
+#[derive(Debug)]
+pub struct MountainCar {
+    goal_velocity: Continous,
+    state: [Continous; 2],
+    rng: NpRandom,
+    obs_space: BoxSpace<Continous>,
+    act_space: DiscreteSpace,
+}
+
+impl MountainCar {
+    const MIN_POSITION: f64 = -1.2;
+    const MAX_POSITION: f64 = 0.6;
+    const MAX_SPEED: f64 = 0.07;
+    const GOAL_POSITION: f64 = 0.5;
+    const FORCE: f64 = 0.001;
+    const GRAVITY: f64 = 0.0025;
+
+    /// Gymnasium's default goal_velocity is 0.
+    pub fn new(goal_velocity: Continous) -> Self {
+        Self {
+            goal_velocity,
+            state: [0.; 2],
+            rng: NpRandom::from_entropy(),
+            obs_space: box_space(
+                &[Self::MIN_POSITION, -Self::MAX_SPEED],
+                &[Self::MAX_POSITION, Self::MAX_SPEED],
+            ),
+            act_space: DiscreteSpace { n: 3 },
+        }
+    }
+}
+
+impl NativeEnvironment for MountainCar {
+    type O = BoxSpace<Continous>;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.state = [self.rng.uniform(-0.6, -0.4), 0.];
+
+        ResetInfo {
+            observation: float32(&self.state),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<BoxSpace<Continous>> {
+        let [mut position, mut velocity] = self.state;
+
+        velocity +=
+            (*action - 1) as Continous * Self::FORCE + (3. * position).cos() * (-Self::GRAVITY);
+        velocity = velocity.clamp(-Self::MAX_SPEED, Self::MAX_SPEED);
+        position += velocity;
+        position = position.clamp(Self::MIN_POSITION, Self::MAX_POSITION);
+        if position == Self::MIN_POSITION && velocity < 0. {
+            velocity = 0.;
+        }
+
+        let terminated = position >= Self::GOAL_POSITION && velocity >= self.goal_velocity;
+        self.state = [position, velocity];
+
+        StepInfo {
+            observation: float32(&self.state),
+            reward: -1.,
+            truncated: false,
+            terminated,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
@@ -0,0 +1,103 @@
+use super::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+use std::f64::consts::PI;
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/pendulum/
+#[derive(Debug)]
+pub struct Pendulum {
+    g: Continous,
+    state: [Continous; 2],
+    rng: NpRandom,
+    obs_space: BoxSpace<Continous>,
+    act_space: BoxSpace<Continous>,
+}
+
+impl Pendulum {
+    const MAX_SPEED: f64 = 8.;
+    const MAX_TORQUE: f64 = 2.;
+    const DT: f64 = 0.05;
+    const M: f64 = 1.;
+    const L: f64 = 1.;
+
+    /// Gymnasium's default g is 10.
+    pub fn new(g: Continous) -> Self {
+        let high = [1., 1., Self::MAX_SPEED];
+
+        Self {
+            g,
+            state: [0.; 2],
+            rng: NpRandom::from_entropy(),
+            obs_space: box_space(&high.map(|x| -x), &high),
+            act_space: box_space(&[-Self::MAX_TORQUE], &[Self::MAX_TORQUE]),
+        }
+    }
+
+    fn observation(&self) -> Vec<Continous> {
+        let [theta, thetadot] = self.state;
+        float32(&[theta.cos(), theta.sin(), thetadot])
+    }
+}
+
+impl NativeEnvironment for Pendulum {
+    type O = BoxSpace<Continous>;
+    type A = BoxSpace<Continous>;
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &BoxSpace<Continous> {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.state = [self.rng.uniform(-PI, PI), self.rng.uniform(-1., 1.)];
+
+        ResetInfo {
+            observation: self.observation(),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Vec<Continous>) -> StepInfo<BoxSpace<Continous>> {
+        let [th, thdot] = self.state;
+
+        // The action is float32 in Gymnasium.
+        let u = (action[0] as f32).clamp(-Self::MAX_TORQUE as f32, Self::MAX_TORQUE as f32);
+        let costs =
+            angle_normalize(th).powi(2) + 0.1 * thdot.powi(2) + 0.001 * (u * u) as Continous;
+        let u = u as Continous;
+
+        let newthdot = thdot
+            + (3. * self.g / (2. * Self::L) * th.sin() + 3. / (Self::M * Self::L.powi(2)) * u)
+                * Self::DT;
+        let newthdot = newthdot.clamp(-Self::MAX_SPEED, Self::MAX_SPEED);
+        let newth = th + newthdot * Self::DT;
+
+        self.state = [newth, newthdot];
+
+        StepInfo {
+            observation: self.observation(),
+            reward: -costs,
+            truncated: false,
+            terminated: false,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
+
+fn angle_normalize(x: Continous) -> Continous {
+    (x + PI).rem_euclid(2. * PI) - PI
+}
@@ -0,0 +1,79 @@
+use crate::common::defs::*;
+use crate::*;
+use rand::Rng;
+
+pub mod classic_control;
+pub mod seeding;
+pub mod toy_text;
+
+pub use classic_control::acrobot::Acrobot;
+pub use classic_control::cartpole::CartPole;
+pub use classic_control::continuous_mountain_car::MountainCarContinuous;
+pub use classic_control::mountain_car::MountainCar;
+pub use classic_control::pendulum::Pendulum;
+pub use seeding::NpRandom;
+pub use toy_text::blackjack::Blackjack;
+pub use toy_text::cliff_walking::CliffWalking;
+pub use toy_text::frozen_lake::FrozenLake;
+pub use toy_text::taxi::Taxi;
+
+/// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.render
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderMode {
+    Ansi,
+}
+
+impl RenderMode {
+    /// From the render_mode kwarg of gymnasium.make.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ansi" => Some(RenderMode::Ansi),
+            _ => None,
+        }
+    }
+}
+
+/// Environment simulated in-process, i.e. without the server. Dynamics, spaces and items are the same as
+/// those of the corresponding Gymnasium environment as seen through Environment.
+/// NOTE: Wrappers applied by gymnasium.make (e.g. TimeLimit) are not part of the environment.
+pub trait NativeEnvironment {
+    type O: Space;
+    type A: Space;
+
+    fn observation_space(&self) -> &Self::O;
+
+    fn action_space(&self) -> &Self::A;
+
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.reset
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O>;
+
+    /// NOTE: The action is expected to be contained in the action space.
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.step
+    fn step(&mut self, action: &<Self::A as Space>::Item) -> StepInfo<Self::O>;
+
+    /// None if the environment was created without a render mode.
+    fn render(&self) -> Option<RenderFrame>;
+
+    /// The model of the environment, for those that expose one. Refer: gymnasium::transitions.
+    fn transitions(&self) -> Option<&Transitions> {
+        None
+    }
+}
+
+/// Same as gymnasium's categorical_sample: index of the first cumulative probability above a uniform sample.
+/// NOTE: 0 when there is none, e.g. the probabilities summing to less than 1 by rounding, same as np.argmax.
+fn categorical_sample<R: Rng + ?Sized>(
+    probs: impl Iterator<Item = Continous>,
+    rng: &mut R,
+) -> usize {
+    let x = rng.gen::<Continous>();
+    let mut cum = 0.;
+    for (i, p) in probs.enumerate() {
+        cum += p;
+        if cum > x {
+            return i;
+        }
+    }
+
+    0
+}
@@ -0,0 +1,205 @@
+use rand::{Error, RngCore, SeedableRng};
+
+/// Same generator as gymnasium.utils.seeding.np_random, i.e. numpy's PCG64 seeded through a SeedSequence,
+/// so that native environments reset to the same states as the Gymnasium ones for the same seed.
+/// NOTE: Only the draws used by the native environments are reproduced, e.g. uniform and random.
+#[derive(Clone, Debug)]
+pub struct NpRandom {
+    state: u128,
+    inc: u128,
+    /// numpy hands out the two halves of a 64 bit draw as consecutive 32 bit draws.
+    uinteger: Option<u32>,
+}
+
+const PCG_MULTIPLIER: u128 = 0x2360ED051FC65DA4_4385DF649FCCF645;
+
+impl NpRandom {
+    /// Same as numpy.random.Generator.random.
+    pub fn random(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1. / 9007199254740992.)
+    }
+
+    /// Same as numpy.random.Generator.uniform.
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.random()
+    }
+
+    /// Same as numpy.random.Generator.integers(low, high), i.e. Lemire's bounded draws with high exclusive.
+    pub fn integers(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low < high, "low >= high");
+        let rng = (high - low - 1) as u64;
+        let x = if rng == 0 {
+            0
+        } else if rng == u32::MAX as u64 {
+            self.next_u32() as u64
+        } else if rng < u32::MAX as u64 {
+            let rng_excl = rng + 1;
+            let mut m = self.next_u32() as u64 * rng_excl;
+            if (m as u32 as u64) < rng_excl {
+                let threshold = (u32::MAX as u64 - rng) % rng_excl;
+                while (m as u32 as u64) < threshold {
+                    m = self.next_u32() as u64 * rng_excl;
+                }
+            }
+            m >> 32
+        } else if rng == u64::MAX {
+            self.next_u64()
+        } else {
+            let rng_excl = rng + 1;
+            let mut m = self.next_u64() as u128 * rng_excl as u128;
+            if (m as u64) < rng_excl {
+                let threshold = (u64::MAX - rng) % rng_excl;
+                while (m as u64) < threshold {
+                    m = self.next_u64() as u128 * rng_excl as u128;
+                }
+            }
+            (m >> 64) as u64
+        };
+
+        low.wrapping_add(x as i64)
+    }
+
+    /// Same as numpy.random.Generator.choice(xs), i.e. uniformly with replacement.
+    pub fn choice<'a, T>(&mut self, xs: &'a [T]) -> &'a T {
+        &xs[self.integers(0, xs.len() as i64) as usize]
+    }
+
+    fn from_entropy_words(entropy: &[u32]) -> Self {
+        let s = seed_sequence_state(entropy);
+        let init_state = ((s[0] as u128) << 64) | s[1] as u128;
+        let init_seq = ((s[2] as u128) << 64) | s[3] as u128;
+
+        let mut rng = Self {
+            state: 0,
+            inc: (init_seq << 1) | 1,
+            uinteger: None,
+        };
+        rng.step();
+        rng.state = rng.state.wrapping_add(init_state);
+        rng.step();
+
+        rng
+    }
+
+    fn step(&mut self) {
+        self.state = self
+            .state
+            .wrapping_mul(PCG_MULTIPLIER)
+            .wrapping_add(self.inc);
+    }
+}
+
+impl RngCore for NpRandom {
+    fn next_u32(&mut self) -> u32 {
+        if let Some(x) = self.uinteger.take() {
+            return x;
+        }
+
+        let x = self.next_u64();
+        self.uinteger = Some((x >> 32) as u32);
+        x as u32
+    }
+
+    /// XSL-RR output of the 128 bit LCG.
+    fn next_u64(&mut self) -> u64 {
+        self.step();
+        let x = ((self.state >> 64) as u64) ^ (self.state as u64);
+        x.rotate_right((self.state >> 122) as u32)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let x = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&x[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for NpRandom {
+    type Seed = [u8; 16];
+
+    /// The seed is the 128 bit entropy of the SeedSequence.
+    fn from_seed(seed: [u8; 16]) -> Self {
+        Self::from_entropy_words(&int_to_words(u128::from_le_bytes(seed)))
+    }
+
+    /// Same as np_random(seed).
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_entropy_words(&int_to_words(seed as u128))
+    }
+}
+
+/// Same as numpy's _int_to_uint32_array.
+fn int_to_words(mut n: u128) -> Vec<u32> {
+    if n == 0 {
+        return vec![0];
+    }
+
+    let mut words = vec![];
+    while n > 0 {
+        words.push(n as u32);
+        n >>= 32;
+    }
+
+    words
+}
+
+/// Same as numpy's SeedSequence(entropy).generate_state(4, np.uint64).
+fn seed_sequence_state(entropy: &[u32]) -> [u64; 4] {
+    const POOL_SIZE: usize = 4;
+    const INIT_A: u32 = 0x43b0d7e5;
+    const MULT_A: u32 = 0x931e8875;
+    const INIT_B: u32 = 0x8b51f9dd;
+    const MULT_B: u32 = 0x58f38ded;
+    const MIX_MULT_L: u32 = 0xca01f9dd;
+    const MIX_MULT_R: u32 = 0x4973f715;
+    const XSHIFT: u32 = 16;
+
+    let mut hash_const = INIT_A;
+    let mut hashmix = |mut value: u32| {
+        value ^= hash_const;
+        hash_const = hash_const.wrapping_mul(MULT_A);
+        value = value.wrapping_mul(hash_const);
+        value ^ (value >> XSHIFT)
+    };
+    let mix = |x: u32, y: u32| {
+        let result = MIX_MULT_L
+            .wrapping_mul(x)
+            .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+        result ^ (result >> XSHIFT)
+    };
+
+    let mut pool = [0u32; POOL_SIZE];
+    for (i, p) in pool.iter_mut().enumerate() {
+        *p = hashmix(entropy.get(i).copied().unwrap_or(0));
+    }
+    for i_src in 0..POOL_SIZE {
+        for i_dst in 0..POOL_SIZE {
+            if i_src != i_dst {
+                pool[i_dst] = mix(pool[i_dst], hashmix(pool[i_src]));
+            }
+        }
+    }
+    for &x in entropy.iter().skip(POOL_SIZE) {
+        for p in pool.iter_mut() {
+            *p = mix(*p, hashmix(x));
+        }
+    }
+
+    let mut hash_const = INIT_B;
+    let words: Vec<u32> = (0..2 * POOL_SIZE)
+        .map(|i| {
+            let mut value = pool[i % POOL_SIZE] ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            value = value.wrapping_mul(hash_const);
+            value ^ (value >> XSHIFT)
+        })
+        .collect();
+
+    [0, 1, 2, 3].map(|i| words[2 * i] as u64 | (words[2 * i + 1] as u64) << 32)
+}
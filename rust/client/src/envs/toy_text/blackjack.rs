@@ -0,0 +1,155 @@
+use crate::common::defs::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+const DECK: [Discrete; 13] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 10, 10, 10];
+
+const STICK: Discrete = 0;
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/blackjack/
+/// Observations are (player's sum, dealer's showing card, usable ace as 0 or 1).
+/// NOTE: Only the rgb_array and human render modes exist in gymnasium, neither is supported.
+#[derive(Debug)]
+pub struct Blackjack {
+    natural: bool,
+    sab: bool,
+    dealer: Vec<Discrete>,
+    player: Vec<Discrete>,
+    rng: NpRandom,
+    obs_space: TupleSpace,
+    act_space: DiscreteSpace,
+}
+
+impl Blackjack {
+    /// Blackjack-v1 is registered with natural = false and sab = true.
+    pub fn new(natural: bool, sab: bool) -> Self {
+        Self {
+            natural,
+            sab,
+            dealer: vec![],
+            player: vec![],
+            rng: NpRandom::from_entropy(),
+            obs_space: TupleSpace {
+                spaces: [32, 11, 2]
+                    .map(|n| AnySpace::Discrete(DiscreteSpace { n }))
+                    .into(),
+            },
+            act_space: DiscreteSpace { n: 2 },
+        }
+    }
+
+    fn draw_card(&mut self) -> Discrete {
+        *self.rng.choice(&DECK)
+    }
+
+    fn observation(&self) -> Vec<AnyItem> {
+        [
+            sum_hand(&self.player),
+            self.dealer[0],
+            usable_ace(&self.player) as Discrete,
+        ]
+        .map(AnyItem::Discrete)
+        .into()
+    }
+}
+
+impl NativeEnvironment for Blackjack {
+    type O = TupleSpace;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &TupleSpace {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        self.dealer = vec![self.draw_card(), self.draw_card()];
+        self.player = vec![self.draw_card(), self.draw_card()];
+
+        // The suit and face of the dealer's card are only rendered, still they are drawn as in gymnasium.
+        self.rng.choice(&["C", "D", "H", "S"]);
+        if self.dealer[0] == 10 {
+            self.rng.choice(&["J", "Q", "K"]);
+        }
+
+        ResetInfo {
+            observation: self.observation(),
+            info: json!({}),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<TupleSpace> {
+        let (terminated, reward) = if *action != STICK {
+            let card = self.draw_card();
+            self.player.push(card);
+            if is_bust(&self.player) {
+                (true, -1.)
+            } else {
+                (false, 0.)
+            }
+        } else {
+            while sum_hand(&self.dealer) < 17 {
+                let card = self.draw_card();
+                self.dealer.push(card);
+            }
+            let (player, dealer) = (score(&self.player), score(&self.dealer));
+            let reward = (player > dealer) as i8 as f64 - (player < dealer) as i8 as f64;
+            let reward = if self.sab && is_natural(&self.player) && !is_natural(&self.dealer) {
+                1.
+            } else if !self.sab && self.natural && is_natural(&self.player) && reward == 1. {
+                1.5
+            } else {
+                reward
+            };
+            (true, reward)
+        };
+
+        StepInfo {
+            observation: self.observation(),
+            reward,
+            truncated: false,
+            terminated,
+            info: json!({}),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        None
+    }
+}
+
+fn usable_ace(hand: &[Discrete]) -> bool {
+    hand.contains(&1) && hand.iter().sum::<Discrete>() + 10 <= 21
+}
+
+fn sum_hand(hand: &[Discrete]) -> Discrete {
+    hand.iter().sum::<Discrete>() + if usable_ace(hand) { 10 } else { 0 }
+}
+
+fn is_bust(hand: &[Discrete]) -> bool {
+    sum_hand(hand) > 21
+}
+
+fn score(hand: &[Discrete]) -> Discrete {
+    if is_bust(hand) {
+        0
+    } else {
+        sum_hand(hand)
+    }
+}
+
+fn is_natural(hand: &[Discrete]) -> bool {
+    let mut hand = hand.to_vec();
+    hand.sort();
+    hand == [1, 10]
+}
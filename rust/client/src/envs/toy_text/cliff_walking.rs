@@ -0,0 +1,154 @@
+use crate::common::defs::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+const UP: Discrete = 0;
+const RIGHT: Discrete = 1;
+const DOWN: Discrete = 2;
+const LEFT: Discrete = 3;
+
+const NROW: usize = 4;
+const NCOL: usize = 12;
+const START_STATE: Discrete = 36;
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/cliff_walking/
+#[derive(Debug)]
+pub struct CliffWalking {
+    p: Transitions,
+    s: Discrete,
+    render_mode: Option<RenderMode>,
+    rng: NpRandom,
+    obs_space: DiscreteSpace,
+    act_space: DiscreteSpace,
+}
+
+impl CliffWalking {
+    pub fn new(render_mode: Option<RenderMode>) -> Self {
+        let mut p = Transitions::new();
+        for s in 0..(NROW * NCOL) {
+            let (row, col) = (s / NCOL, s % NCOL);
+            for (a, (dr, dc)) in [
+                (UP, (-1, 0)),
+                (RIGHT, (0, 1)),
+                (DOWN, (1, 0)),
+                (LEFT, (0, -1)),
+            ] {
+                let row = (row as isize + dr).clamp(0, NROW as isize - 1) as usize;
+                let col = (col as isize + dc).clamp(0, NCOL as isize - 1) as usize;
+                let t = if Self::is_cliff(row, col) {
+                    Transition {
+                        next_state: START_STATE,
+                        probability: 1.,
+                        reward: -100.,
+                        done: false,
+                    }
+                } else {
+                    Transition {
+                        next_state: (row * NCOL + col) as Discrete,
+                        probability: 1.,
+                        reward: -1.,
+                        done: (row, col) == (NROW - 1, NCOL - 1),
+                    }
+                };
+                p.insert((s as Discrete, a), vec![t]);
+            }
+        }
+
+        Self {
+            p,
+            s: START_STATE,
+            render_mode,
+            rng: NpRandom::from_entropy(),
+            obs_space: DiscreteSpace {
+                n: (NROW * NCOL) as Discrete,
+            },
+            act_space: DiscreteSpace { n: 4 },
+        }
+    }
+
+    fn is_cliff(row: usize, col: usize) -> bool {
+        row == NROW - 1 && (1..NCOL - 1).contains(&col)
+    }
+}
+
+impl NativeEnvironment for CliffWalking {
+    type O = DiscreteSpace;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &DiscreteSpace {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        // The initial state distribution is all on the start state, still a draw is made as in gymnasium.
+        let n = NROW * NCOL;
+        let i = categorical_sample(
+            (0..n).map(|s| if s as Discrete == START_STATE { 1. } else { 0. }),
+            &mut self.rng,
+        );
+        self.s = i as Discrete;
+
+        ResetInfo {
+            observation: self.s,
+            info: json!({ "prob": 1 }),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<DiscreteSpace> {
+        let ts = &self.p[&(self.s, *action)];
+        let i = categorical_sample(ts.iter().map(|t| t.probability), &mut self.rng);
+        let t = &ts[i];
+        self.s = t.next_state;
+
+        StepInfo {
+            observation: t.next_state,
+            reward: t.reward,
+            truncated: false,
+            terminated: t.done,
+            info: json!({ "prob": t.probability }),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        match self.render_mode? {
+            RenderMode::Ansi => {
+                let mut out = String::new();
+                for s in 0..(NROW * NCOL) {
+                    let (row, col) = (s / NCOL, s % NCOL);
+                    let cell = if self.s == s as Discrete {
+                        "x"
+                    } else if (row, col) == (NROW - 1, NCOL - 1) {
+                        "T"
+                    } else if Self::is_cliff(row, col) {
+                        "C"
+                    } else {
+                        "o"
+                    };
+                    match col {
+                        0 => out.push_str(&format!("{cell} ")),
+                        c if c == NCOL - 1 => out.push_str(&format!(" {cell}\n")),
+                        _ => out.push_str(&format!(" {cell} ")),
+                    }
+                }
+                out.push('\n');
+
+                Some(RenderFrame::Ansi(out))
+            }
+        }
+    }
+
+    fn transitions(&self) -> Option<&Transitions> {
+        Some(&self.p)
+    }
+}
@@ -0,0 +1,264 @@
+use super::*;
+use crate::common::{defs::*, error::*};
+use crate::envs::*;
+use rand::{Rng, SeedableRng};
+use serde_json::json;
+
+const LEFT: Discrete = 0;
+const DOWN: Discrete = 1;
+const RIGHT: Discrete = 2;
+const UP: Discrete = 3;
+
+const ACTION_NAMES: [&str; 4] = ["Left", "Down", "Right", "Up"];
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/frozen_lake/
+#[derive(Debug)]
+pub struct FrozenLake {
+    desc: Vec<Vec<u8>>,
+    ncol: usize,
+    p: Transitions,
+    initial_states: Vec<Discrete>,
+    s: Discrete,
+    last_action: Option<Discrete>,
+    render_mode: Option<RenderMode>,
+    rng: NpRandom,
+    obs_space: DiscreteSpace,
+    act_space: DiscreteSpace,
+}
+
+impl FrozenLake {
+    /// The predefined maps, by map_name.
+    pub fn map(name: &str) -> Option<&'static [&'static str]> {
+        match name {
+            "4x4" => Some(&["SFFF", "FHFH", "FFFH", "HFFG"]),
+            "8x8" => Some(&[
+                "SFFFFFFF", "FFFFFFFF", "FFFHFFFF", "FFFFFHFF", "FFFHFFFF", "FHHFFFHF", "FHFFHFHF",
+                "FFFHFFFG",
+            ]),
+            _ => None,
+        }
+    }
+
+    /// S: start, F: frozen, H: hole, G: goal. One row per string, all of the same length.
+    /// NOTE: The map must not be empty and have at least one start, otherwise GymError::InvalidArgument.
+    pub fn new<S: AsRef<str>>(
+        desc: &[S],
+        is_slippery: bool,
+        render_mode: Option<RenderMode>,
+    ) -> GymResult<Self> {
+        let desc: Vec<Vec<u8>> = desc.iter().map(|r| r.as_ref().bytes().collect()).collect();
+        Self::validate(&desc)?;
+        let (nrow, ncol) = (desc.len(), desc[0].len());
+        let to_s = |row: usize, col: usize| (row * ncol + col) as Discrete;
+
+        let initial_states = (0..nrow)
+            .flat_map(|row| (0..ncol).map(move |col| (row, col)))
+            .filter(|&(row, col)| desc[row][col] == b'S')
+            .map(|(row, col)| to_s(row, col))
+            .collect();
+
+        let mut p = Transitions::new();
+        for row in 0..nrow {
+            for col in 0..ncol {
+                let s = to_s(row, col);
+                for a in [LEFT, DOWN, RIGHT, UP] {
+                    let transition = |probability, b| {
+                        let (row, col) = Self::inc(row, col, b, nrow, ncol);
+                        let letter = desc[row][col];
+                        Transition {
+                            next_state: to_s(row, col),
+                            probability,
+                            reward: if letter == b'G' { 1. } else { 0. },
+                            done: letter == b'G' || letter == b'H',
+                        }
+                    };
+
+                    let ts = if matches!(desc[row][col], b'G' | b'H') {
+                        vec![Transition {
+                            next_state: s,
+                            probability: 1.,
+                            reward: 0.,
+                            done: true,
+                        }]
+                    } else if is_slippery {
+                        [(a + 3) % 4, a, (a + 1) % 4]
+                            .into_iter()
+                            .map(|b| transition(1. / 3., b))
+                            .collect()
+                    } else {
+                        vec![transition(1., a)]
+                    };
+                    p.insert((s, a), ts);
+                }
+            }
+        }
+
+        Ok(Self {
+            obs_space: DiscreteSpace {
+                n: (nrow * ncol) as Discrete,
+            },
+            act_space: DiscreteSpace { n: 4 },
+            desc,
+            ncol,
+            p,
+            initial_states,
+            s: 0,
+            last_action: None,
+            render_mode,
+            rng: NpRandom::from_entropy(),
+        })
+    }
+
+    fn validate(desc: &[Vec<u8>]) -> GymResult<()> {
+        let invalid = |what: &str| Err(GymError::InvalidArgument(format!("FrozenLake map {what}")));
+
+        let Some(ncol) = desc.first().map(Vec::len).filter(|&ncol| ncol > 0) else {
+            return invalid("is empty");
+        };
+        if desc.iter().any(|row| row.len() != ncol) {
+            return invalid("rows are not all of the same length");
+        }
+        if let Some(&tile) = desc.iter().flatten().find(|t| !b"SFHG".contains(t)) {
+            return invalid(&format!("has an unknown tile '{}'", tile.escape_ascii()));
+        }
+        if !desc.iter().flatten().any(|&t| t == b'S') {
+            return invalid("has no start tile 'S'");
+        }
+
+        Ok(())
+    }
+
+    /// Same as gymnasium's generate_random_map: frozen tiles with probability p, with a path from start to goal.
+    pub fn generate_random_map<R: Rng + ?Sized>(size: usize, p: f64, rng: &mut R) -> Vec<String> {
+        loop {
+            let mut board: Vec<Vec<u8>> = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.gen_bool(p.min(1.)) { b'F' } else { b'H' })
+                        .collect()
+                })
+                .collect();
+            board[0][0] = b'S';
+            board[size - 1][size - 1] = b'G';
+
+            if Self::is_valid(&board) {
+                return board
+                    .into_iter()
+                    .map(|r| String::from_utf8(r).unwrap())
+                    .collect();
+            }
+        }
+    }
+
+    fn is_valid(board: &[Vec<u8>]) -> bool {
+        let size = board.len();
+        let mut discovered = vec![vec![false; size]; size];
+        let mut frontier = vec![(0, 0)];
+        while let Some((r, c)) = frontier.pop() {
+            if discovered[r][c] {
+                continue;
+            }
+            discovered[r][c] = true;
+            for (dr, dc) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                let (r, c) = (r as isize + dr, c as isize + dc);
+                if r < 0 || r >= size as isize || c < 0 || c >= size as isize {
+                    continue;
+                }
+                let (r, c) = (r as usize, c as usize);
+                match board[r][c] {
+                    b'G' => return true,
+                    b'H' => {}
+                    _ => frontier.push((r, c)),
+                }
+            }
+        }
+
+        false
+    }
+
+    fn inc(row: usize, col: usize, a: Discrete, nrow: usize, ncol: usize) -> (usize, usize) {
+        match a {
+            LEFT => (row, col.saturating_sub(1)),
+            DOWN => ((row + 1).min(nrow - 1), col),
+            RIGHT => (row, (col + 1).min(ncol - 1)),
+            UP => (row.saturating_sub(1), col),
+            _ => (row, col),
+        }
+    }
+}
+
+impl NativeEnvironment for FrozenLake {
+    type O = DiscreteSpace;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &DiscreteSpace {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        let n = self.initial_states.len();
+        let i = categorical_sample((0..n).map(|_| 1. / n as Continous), &mut self.rng);
+        self.s = self.initial_states[i];
+        self.last_action = None;
+
+        ResetInfo {
+            observation: self.s,
+            info: json!({ "prob": 1 }),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<DiscreteSpace> {
+        let ts = &self.p[&(self.s, *action)];
+        let i = categorical_sample(ts.iter().map(|t| t.probability), &mut self.rng);
+        let t = &ts[i];
+        self.s = t.next_state;
+        self.last_action = Some(*action);
+
+        StepInfo {
+            observation: t.next_state,
+            reward: t.reward,
+            truncated: false,
+            terminated: t.done,
+            info: json!({ "prob": t.probability }),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        match self.render_mode? {
+            RenderMode::Ansi => {
+                let (row, col) = (self.s as usize / self.ncol, self.s as usize % self.ncol);
+                let mut out = match self.last_action {
+                    Some(a) => format!("  ({})\n", ACTION_NAMES[a as usize]),
+                    None => "\n".to_string(),
+                };
+                for (r, line) in self.desc.iter().enumerate() {
+                    for (c, &letter) in line.iter().enumerate() {
+                        let letter = (letter as char).to_string();
+                        if (r, c) == (row, col) {
+                            out.push_str(&colorize(&letter, RED, false, true));
+                        } else {
+                            out.push_str(&letter);
+                        }
+                    }
+                    out.push('\n');
+                }
+
+                Some(RenderFrame::Ansi(out))
+            }
+        }
+    }
+
+    fn transitions(&self) -> Option<&Transitions> {
+        Some(&self.p)
+    }
+}
@@ -0,0 +1,22 @@
+pub mod blackjack;
+pub mod cliff_walking;
+pub mod frozen_lake;
+pub mod taxi;
+
+pub(crate) const RED: u8 = 31;
+pub(crate) const GREEN: u8 = 32;
+pub(crate) const YELLOW: u8 = 33;
+pub(crate) const BLUE: u8 = 34;
+pub(crate) const MAGENTA: u8 = 35;
+
+/// Same as gymnasium.utils.colorize, for the ansi renderings.
+pub(crate) fn colorize(s: &str, color: u8, bold: bool, highlight: bool) -> String {
+    let color = if highlight { color + 10 } else { color };
+    let attrs = if bold {
+        format!("{color};1")
+    } else {
+        color.to_string()
+    };
+
+    format!("\x1b[{attrs}m{s}\x1b[0m")
+}
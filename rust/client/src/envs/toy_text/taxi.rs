@@ -0,0 +1,248 @@
+use super::*;
+use crate::common::defs::*;
+use crate::envs::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+const MAP: [&str; 7] = [
+    "+---------+",
+    "|R: | : :G|",
+    "| : | : : |",
+    "| : : : : |",
+    "| | : | : |",
+    "|Y| : |B: |",
+    "+---------+",
+];
+
+const LOCS: [(usize, usize); 4] = [(0, 0), (0, 4), (4, 0), (4, 3)];
+
+const ACTION_NAMES: [&str; 6] = ["South", "North", "East", "West", "Pickup", "Dropoff"];
+
+const NUM_STATES: usize = 500;
+const MAX_ROW: usize = 4;
+const MAX_COL: usize = 4;
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/taxi/
+#[derive(Debug)]
+pub struct Taxi {
+    desc: Vec<Vec<u8>>,
+    p: Transitions,
+    initial_state_distrib: Vec<Continous>,
+    s: Discrete,
+    last_action: Option<Discrete>,
+    render_mode: Option<RenderMode>,
+    rng: NpRandom,
+    obs_space: DiscreteSpace,
+    act_space: DiscreteSpace,
+}
+
+impl Taxi {
+    pub fn new(render_mode: Option<RenderMode>) -> Self {
+        let desc: Vec<Vec<u8>> = MAP.iter().map(|r| r.bytes().collect()).collect();
+
+        let mut initial_state_distrib = vec![0.; NUM_STATES];
+        let mut p = Transitions::new();
+        for row in 0..=MAX_ROW {
+            for col in 0..=MAX_COL {
+                for pass_idx in 0..=LOCS.len() {
+                    for (dest_idx, &dest) in LOCS.iter().enumerate() {
+                        let state = Self::encode(row, col, pass_idx, dest_idx);
+                        if pass_idx < 4 && pass_idx != dest_idx {
+                            initial_state_distrib[state as usize] += 1.;
+                        }
+                        for action in 0..6 {
+                            let (mut new_row, mut new_col, mut new_pass_idx) = (row, col, pass_idx);
+                            let mut reward = -1.;
+                            let mut terminated = false;
+                            let taxi_loc = (row, col);
+
+                            match action {
+                                0 => new_row = (row + 1).min(MAX_ROW),
+                                1 => new_row = row.saturating_sub(1),
+                                2 if desc[1 + row][2 * col + 2] == b':' => {
+                                    new_col = (col + 1).min(MAX_COL)
+                                }
+                                3 if desc[1 + row][2 * col] == b':' => {
+                                    new_col = col.saturating_sub(1)
+                                }
+                                4 => {
+                                    if LOCS.get(pass_idx) == Some(&taxi_loc) {
+                                        new_pass_idx = 4;
+                                    } else {
+                                        reward = -10.;
+                                    }
+                                }
+                                5 => {
+                                    if taxi_loc == dest && pass_idx == 4 {
+                                        new_pass_idx = dest_idx;
+                                        terminated = true;
+                                        reward = 20.;
+                                    } else if let (Some(i), 4) =
+                                        (LOCS.iter().position(|&l| l == taxi_loc), pass_idx)
+                                    {
+                                        new_pass_idx = i;
+                                    } else {
+                                        reward = -10.;
+                                    }
+                                }
+                                _ => {}
+                            }
+
+                            p.insert(
+                                (state, action),
+                                vec![Transition {
+                                    next_state: Self::encode(
+                                        new_row,
+                                        new_col,
+                                        new_pass_idx,
+                                        dest_idx,
+                                    ),
+                                    probability: 1.,
+                                    reward,
+                                    done: terminated,
+                                }],
+                            );
+                        }
+                    }
+                }
+            }
+        }
+        let total: Continous = initial_state_distrib.iter().sum();
+        initial_state_distrib.iter_mut().for_each(|x| *x /= total);
+
+        Self {
+            desc,
+            p,
+            initial_state_distrib,
+            s: 0,
+            last_action: None,
+            render_mode,
+            rng: NpRandom::from_entropy(),
+            obs_space: DiscreteSpace {
+                n: NUM_STATES as Discrete,
+            },
+            act_space: DiscreteSpace { n: 6 },
+        }
+    }
+
+    pub fn encode(taxi_row: usize, taxi_col: usize, pass_loc: usize, dest_idx: usize) -> Discrete {
+        (((taxi_row * 5 + taxi_col) * 5 + pass_loc) * 4 + dest_idx) as Discrete
+    }
+
+    /// Inverse of encode, i.e. (taxi_row, taxi_col, pass_loc, dest_idx).
+    pub fn decode(s: Discrete) -> (usize, usize, usize, usize) {
+        let s = s as usize;
+        (s / 100, s / 20 % 5, s / 4 % 5, s % 4)
+    }
+
+    /// Same as info["action_mask"], 1 for the actions that change the state.
+    pub fn action_mask(&self, s: Discrete) -> [u8; 6] {
+        let (taxi_row, taxi_col, pass_loc, dest_idx) = Self::decode(s);
+        let taxi_loc = (taxi_row, taxi_col);
+
+        [
+            taxi_row < 4,
+            taxi_row > 0,
+            taxi_col < 4 && self.desc[taxi_row + 1][2 * taxi_col + 2] == b':',
+            taxi_col > 0 && self.desc[taxi_row + 1][2 * taxi_col] == b':',
+            pass_loc < 4 && taxi_loc == LOCS[pass_loc],
+            pass_loc == 4 && (taxi_loc == LOCS[dest_idx] || LOCS.contains(&taxi_loc)),
+        ]
+        .map(u8::from)
+    }
+}
+
+impl NativeEnvironment for Taxi {
+    type O = DiscreteSpace;
+    type A = DiscreteSpace;
+
+    fn observation_space(&self) -> &DiscreteSpace {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &DiscreteSpace {
+        &self.act_space
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> ResetInfo<Self::O> {
+        if let Some(seed) = seed {
+            self.rng = NpRandom::seed_from_u64(seed);
+        }
+
+        let i = categorical_sample(self.initial_state_distrib.iter().copied(), &mut self.rng);
+        self.s = i as Discrete;
+        self.last_action = None;
+
+        ResetInfo {
+            observation: self.s,
+            info: json!({ "prob": 1., "action_mask": self.action_mask(self.s) }),
+        }
+    }
+
+    fn step(&mut self, action: &Discrete) -> StepInfo<DiscreteSpace> {
+        let ts = &self.p[&(self.s, *action)];
+        let i = categorical_sample(ts.iter().map(|t| t.probability), &mut self.rng);
+        let t = &ts[i];
+        self.s = t.next_state;
+        self.last_action = Some(*action);
+
+        StepInfo {
+            observation: t.next_state,
+            reward: t.reward,
+            truncated: false,
+            terminated: t.done,
+            info: json!({ "prob": t.probability, "action_mask": self.action_mask(self.s) }),
+            final_observation: None,
+            final_info: None,
+        }
+    }
+
+    fn render(&self) -> Option<RenderFrame> {
+        match self.render_mode? {
+            RenderMode::Ansi => {
+                let mut out: Vec<Vec<String>> = self
+                    .desc
+                    .iter()
+                    .map(|line| line.iter().map(|&c| (c as char).to_string()).collect())
+                    .collect();
+                let (taxi_row, taxi_col, pass_idx, dest_idx) = Self::decode(self.s);
+                let taxi = &mut out[1 + taxi_row][2 * taxi_col + 1];
+
+                if pass_idx < 4 {
+                    *taxi = colorize(taxi, YELLOW, false, true);
+                    let (pi, pj) = LOCS[pass_idx];
+                    let passenger = &mut out[1 + pi][2 * pj + 1];
+                    *passenger = colorize(passenger, BLUE, true, false);
+                } else {
+                    // Passenger in the taxi.
+                    let ul = if taxi.as_str() == " " {
+                        "_"
+                    } else {
+                        taxi.as_str()
+                    };
+                    *taxi = colorize(ul, GREEN, false, true);
+                }
+                let (di, dj) = LOCS[dest_idx];
+                let dest = &mut out[1 + di][2 * dj + 1];
+                *dest = colorize(dest, MAGENTA, false, false);
+
+                let mut out = out
+                    .iter()
+                    .map(|row| row.concat())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                out.push('\n');
+                match self.last_action {
+                    Some(a) => out.push_str(&format!("  ({})\n", ACTION_NAMES[a as usize])),
+                    None => out.push('\n'),
+                }
+
+                Some(RenderFrame::Ansi(out))
+            }
+        }
+    }
+
+    fn transitions(&self) -> Option<&Transitions> {
+        Some(&self.p)
+    }
+}
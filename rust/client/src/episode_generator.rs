@@ -0,0 +1,72 @@
+use crate::common::{defs::*, error::*};
+use crate::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Episodes of any policy, rolled out client-side on any Env. Unlike the server's episodes endpoint,
+/// which samples random actions, e.g. to evaluate a given policy via Monte Carlo.
+/// Episode i of generate(n, Some(seed)) is seeded with seed + i, i.e. each one is reproducible.
+/// NOTE: Truncated episodes end the same as terminated ones, i.e. their last state is not bootstrapped.
+pub struct PolicyEpisodeGenerator<E: Env> {
+    env: RefCell<E>,
+    policy: Rc<dyn Policy<E::O, E::A>>,
+    max_episode_steps: Option<usize>,
+}
+
+impl<E: Env> PolicyEpisodeGenerator<E> {
+    /// Episodes are truncated after max_episode_steps steps, if any. Refer: rollout_with_limit.
+    pub fn new(
+        env: E,
+        policy: Rc<dyn Policy<E::O, E::A>>,
+        max_episode_steps: Option<usize>,
+    ) -> Self {
+        Self {
+            env: RefCell::new(env),
+            policy,
+            max_episode_steps,
+        }
+    }
+
+    pub fn into_inner(self) -> E {
+        self.env.into_inner()
+    }
+
+    /// Initial observation and the steps taken from it. Refer: EpisodeGenerator::generate.
+    pub fn episode(&self, seed: Option<u64>) -> GymResult<Episode<E::O>> {
+        rollout_with_limit(
+            &mut *self.env.borrow_mut(),
+            &*self.policy,
+            seed,
+            self.max_episode_steps,
+        )
+    }
+
+    /// Same as episode, along with the actions and infos.
+    pub fn trajectory(&self, seed: Option<u64>) -> GymResult<Trajectory<E::O, E::A>>
+    where
+        <E::O as Space>::Item: Clone,
+    {
+        Trajectory::record(
+            &mut *self.env.borrow_mut(),
+            &*self.policy,
+            seed,
+            self.max_episode_steps,
+        )
+    }
+}
+
+impl<E, T> EpisodeGenerator<T> for PolicyEpisodeGenerator<E>
+where
+    E: Env,
+    <E::O as Space>::Item: EpisodeState<T> + Clone,
+    for<'de> T: Deserialize<'de>,
+{
+    fn generate(&self, n: usize, seed: Option<usize>) -> GymResult<Vec<Vec<EpisodeEvent<T>>>> {
+        (0..n)
+            .map(|i| {
+                self.trajectory(seed.map(|s| (s as u64).wrapping_add(i as u64)))
+                    .map(|t| t.episode_events())
+            })
+            .collect()
+    }
+}
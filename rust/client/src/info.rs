@@ -0,0 +1,80 @@
+//! Typed info of the environments, decoded via StepInfo::info_as or ResetInfo::info_as.
+//! Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.step
+
+use crate::common::{error::*, utils::*, wire_format::*};
+use crate::value_extensions::*;
+use crate::*;
+use serde::{Deserializer, Serialize};
+
+/// Info of FrozenLake and CliffWalking.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct TransitionInfo {
+    /// Probability of the transition taken, 1 unless slippery.
+    pub prob: f64,
+}
+
+/// Info of Taxi.
+/// Refer: https://gymnasium.farama.org/environments/toy_text/taxi/#information
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct TaxiInfo {
+    pub prob: f64,
+    /// 1 for the actions that change the state, in the order of the action space.
+    #[serde(deserialize_with = "deserialize_array")]
+    pub action_mask: Vec<u8>,
+}
+
+impl TaxiInfo {
+    pub fn is_valid(&self, action: Discrete) -> bool {
+        usize::try_from(action)
+            .ok()
+            .and_then(|a| self.action_mask.get(a))
+            .is_some_and(|m| *m == 1)
+    }
+}
+
+/// Statistics RecordEpisodeStatistics adds to the info of the last step of an episode.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct EpisodeStatistics {
+    /// Cumulative reward.
+    pub r: f64,
+    /// Length in steps.
+    pub l: usize,
+    /// Elapsed seconds since the start of the episode.
+    pub t: f64,
+}
+
+/// Info of any environment wrapped in RecordEpisodeStatistics, the episode being set once it ends.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct EpisodeInfo {
+    #[serde(default)]
+    pub episode: Option<EpisodeStatistics>,
+}
+
+/// For #[serde(deserialize_with)] on the numpy arrays of info, flattened. Refer: array_from_value.
+pub fn deserialize_array<'de, D, T>(d: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromCustom,
+{
+    let val = Value::deserialize(d)?;
+    array_from_value(&val).map_err(serde::de::Error::custom)
+}
+
+/// Numpy arrays are sent as binary streams by the Python server, same as observations, while native
+/// environments send plain arrays.
+pub fn array_from_value<T: FromCustom>(val: &Value) -> GymResult<Vec<T>> {
+    match val {
+        Value::Array(xs) => xs
+            .iter()
+            .map(|x| {
+                T::from_value(x).ok_or_else(|| {
+                    GymError::MalformedJson(format!("invalid {} {x}", std::any::type_name::<T>()))
+                })
+            })
+            .collect(),
+        _ => deserialize_binary_stream_value(
+            field(val, "type", Value::as_str)?,
+            &WireValue::from(&val["data"]),
+        ),
+    }
+}
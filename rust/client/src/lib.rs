@@ -3,26 +3,80 @@ extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
 
+pub mod any_space;
+pub mod async_env;
 pub mod common;
+pub mod composite_space;
+pub mod env;
+pub mod envs;
+pub mod episode_generator;
+pub mod info;
+pub mod rgba_frame;
+pub mod trajectory;
+pub mod transition_model;
 pub mod ui;
-
-use common::{defs::*, utils::*};
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+pub mod vec_env;
+pub mod wrappers;
+
+pub use any_space::{AnyItem, AnySpace};
+pub use async_env::{AsyncClient, AsyncEnvironment};
+pub use composite_space::{DictSpace, TupleSpace};
+pub use env::{rollout, rollout_with_limit, Env, Episode, LocalEnvironment};
+pub use episode_generator::PolicyEpisodeGenerator;
+pub use rgba_frame::RgbaFrame;
+pub use trajectory::{Trajectory, TrajectoryStep};
+pub use transition_model::{FiniteSpace, TransitionCache, TransitionModel};
+pub use vec_env::VecEnvironment;
+
+use common::{defs::*, error::*, utils::*, wire_format::*};
+use ndarray::{ArrayD, IxDyn};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use reqwest::blocking::Response;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use serde_json::{to_value, Value};
+use serde_json::{to_value, Map, Value};
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 use std::convert::TryInto;
-use std::error::*;
 use std::rc::Rc;
+use transition_model::*;
 use value_extensions::*;
 
 #[derive(Debug)]
 pub enum RenderFrame {
     Ansi(String),
+    /// Rows, cols and base64(zlib(rgba bytes)), as sent in JSON.
     Rgb(usize, usize, String),
+    /// As sent in MessagePack, i.e. already decoded.
+    Rgba(RgbaFrame),
 }
 
 impl RenderFrame {
+    fn from_value(frame: &WireValue) -> GymResult<Self> {
+        let rf = frame.value;
+        if let Some(rf) = rf.as_str() {
+            Ok(RenderFrame::Ansi(rf.to_string()))
+        } else if rf.is_object() {
+            let rows = field(rf, "rows", Value::as_u64)? as usize;
+            let cols = field(rf, "cols", Value::as_u64)? as usize;
+            match &rf["data"] {
+                Value::String(data) => Ok(RenderFrame::Rgb(rows, cols, data.clone())),
+                _ => Ok(RenderFrame::Rgba(RgbaFrame::new(
+                    cols as u32,
+                    rows as u32,
+                    frame.get("data").bytes()?.into_owned(),
+                )?)),
+            }
+        } else {
+            Err(GymError::MalformedJson(format!(
+                "unsupported render_frame {rf}"
+            )))
+        }
+    }
+
     pub fn as_str(&self) -> Option<&str> {
         match self {
             RenderFrame::Ansi(s) => Some(s),
@@ -36,9 +90,23 @@ impl RenderFrame {
             _ => None,
         }
     }
+
+    /// Decoded rgb_array frame. Refer: RgbaFrame.
+    pub fn to_rgba(&self) -> GymResult<RgbaFrame> {
+        RgbaFrame::try_from(self)
+    }
+
+    /// Decoded rgb_array frame of shape (rows, cols, 4).
+    pub fn to_array(&self) -> GymResult<ndarray::Array3<u8>> {
+        Ok(self.to_rgba()?.into())
+    }
+
+    pub fn save_png<P: AsRef<std::path::Path>>(&self, path: P) -> GymResult<()> {
+        self.to_rgba()?.save_png(path)
+    }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct DiscreteSpace {
     pub n: Discrete,
 }
@@ -46,96 +114,347 @@ pub struct DiscreteSpace {
 impl Space for DiscreteSpace {
     type Item = Discrete;
 
-    fn new(val: &Value) -> Self {
-        let info = val["info"].as_object().unwrap();
-        let name = info["name"].as_str().unwrap();
-        if name != "Discrete" {
-            panic!("name must be Discrete for Discrete spaces.")
-        }
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "Discrete")?;
 
-        Self {
-            n: Discrete::from_value(&info["n"]).unwrap(),
-        }
+        Ok(Self {
+            n: field(info, "n", Discrete::from_value)?,
+        })
     }
 
-    fn action(val: &Value) -> Discrete {
-        Discrete::from_value(val).unwrap()
+    fn action(&self, val: &Value) -> GymResult<Discrete> {
+        Discrete::from_value(val)
+            .ok_or_else(|| GymError::MalformedJson(format!("invalid Discrete action {val}")))
     }
 
-    fn observation(val: &Value) -> Discrete {
-        let ty = val["type"].as_str().unwrap();
-        let data = val["data"].as_str().unwrap();
+    fn observation(&self, val: &WireValue) -> GymResult<Discrete> {
+        let ty = field(val.value, "type", Value::as_str)?;
+        let data = &val.get("data");
 
-        let obs = deserialize_binary_stream::<Discrete>(ty, data);
+        let obs = deserialize_binary_stream_value::<Discrete>(ty, data)?;
 
         if obs.len() != 1 {
-            panic!("For Discrete space: Expected only one observation.")
+            return Err(GymError::MalformedJson(format!(
+                "expected one Discrete observation, got {}",
+                obs.len()
+            )));
         }
 
-        obs[0]
+        Ok(obs[0])
     }
 
-    fn action_request(action: &Discrete) -> HashMap<&str, Value> {
+    fn action_request(&self, action: &Discrete) -> HashMap<&str, Value> {
         let mut req = HashMap::from([]);
         req.insert("action", to_value(action).unwrap());
 
         req
     }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Discrete> {
+        Ok(rng.gen_range(0..self.n))
+    }
+
+    fn contains(&self, item: &Discrete) -> bool {
+        (0..self.n).contains(item)
+    }
 }
 
+/// Element types items are decoded into, whatever the dtype on the wire, as long as no value is lost.
 pub trait FromCustom: Sized + core::fmt::Debug {
     fn from_value(val: &Value) -> Option<Self>;
 
-    fn from_le_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>>;
+    /// None if x does not fit, i.e. the conversion would be lossy.
+    fn from_i64(x: i64) -> Option<Self>;
+
+    /// None if x does not fit, i.e. the conversion would be lossy.
+    fn from_f64(x: f64) -> Option<Self>;
 }
 
-pub trait BoxSpaceElement: FromCustom + Serialize {}
+pub trait BoxSpaceElement: FromCustom + Serialize + PartialOrd + Copy {
+    /// Sample between the bounds of one dimension of a BoxSpace.
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self;
 
-impl BoxSpaceElement for Discrete {}
+    /// Whether the bound is infinite, i.e. the dimension is unbounded on that side.
+    fn is_unbounded(self) -> bool {
+        false
+    }
 
-impl BoxSpaceElement for Continous {}
+    /// Bound as sent by the server, which replaces infinity with the extreme finite float64s.
+    fn from_bound(x: Self) -> Self {
+        x
+    }
+}
 
-#[derive(Debug)]
+impl BoxSpaceElement for Discrete {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        rng.gen_range(low..=high)
+    }
+}
+
+impl BoxSpaceElement for i32 {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        rng.gen_range(low..=high)
+    }
+}
+
+impl BoxSpaceElement for i8 {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        rng.gen_range(low..=high)
+    }
+}
+
+/// E.g. the pixels of image observations.
+impl BoxSpaceElement for u8 {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        rng.gen_range(low..=high)
+    }
+}
+
+/// Same as gymnasium: uniform if bounded, normal if unbounded, shifted exponential if bounded on one side.
+impl BoxSpaceElement for Continous {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        match (low.is_finite(), high.is_finite()) {
+            (true, true) => rng.gen_range(low..=high),
+            (true, false) => low + sample_exponential(rng),
+            (false, true) => high - sample_exponential(rng),
+            (false, false) => sample_standard_normal(rng),
+        }
+    }
+
+    fn is_unbounded(self) -> bool {
+        self.is_infinite()
+    }
+
+    fn from_bound(x: Self) -> Self {
+        if x <= Continous::MIN {
+            Continous::NEG_INFINITY
+        } else if x >= Continous::MAX {
+            Continous::INFINITY
+        } else {
+            x
+        }
+    }
+}
+
+/// NOTE: Bounds are widened to float64 on the wire, the extreme ones are already infinite as float32.
+impl BoxSpaceElement for f32 {
+    fn sample<R: Rng + ?Sized>(low: Self, high: Self, rng: &mut R) -> Self {
+        Continous::sample(low.into(), high.into(), rng) as Self
+    }
+
+    fn is_unbounded(self) -> bool {
+        self.is_infinite()
+    }
+}
+
+#[derive(Clone, Debug)]
 pub struct BoxSpace<T: BoxSpaceElement> {
     pub shape: Vec<usize>,
     pub high: Vec<T>,
     pub low: Vec<T>,
 }
+
+/// Items, low and high are flat i.e. row major, the below shape them as per shape.
+impl<T: BoxSpaceElement> BoxSpace<T> {
+    /// Item as an array of the space's shape, e.g. (96, 96, 3) for the pixels of CarRacing.
+    pub fn shaped(&self, item: Vec<T>) -> GymResult<ArrayD<T>> {
+        let len = item.len();
+        ArrayD::from_shape_vec(IxDyn(&self.shape), item).map_err(|_| {
+            GymError::MalformedJson(format!(
+                "{len} elements do not fit a Box of shape {:?}",
+                self.shape
+            ))
+        })
+    }
+
+    pub fn low_array(&self) -> GymResult<ArrayD<T>> {
+        self.shaped(self.low.clone())
+    }
+
+    pub fn high_array(&self) -> GymResult<ArrayD<T>> {
+        self.shaped(self.high.clone())
+    }
+
+    /// Per dimension, whether low is finite.
+    /// Refer: https://gymnasium.farama.org/api/spaces/fundamental/#gymnasium.spaces.Box.is_bounded
+    pub fn is_bounded_below(&self) -> Vec<bool> {
+        self.low.iter().map(|l| !l.is_unbounded()).collect()
+    }
+
+    /// Per dimension, whether high is finite.
+    pub fn is_bounded_above(&self) -> Vec<bool> {
+        self.high.iter().map(|h| !h.is_unbounded()).collect()
+    }
+
+    /// Whether all dimensions are bounded on both sides.
+    pub fn is_bounded(&self) -> bool {
+        self.low.iter().chain(&self.high).all(|x| !x.is_unbounded())
+    }
+}
+
+fn bounds_from_value<T: BoxSpaceElement>(val: &Value) -> GymResult<Vec<T>> {
+    Ok(array_from_value::<T>(val)?
+        .into_iter()
+        .map(T::from_bound)
+        .collect())
+}
+
 impl<T: BoxSpaceElement> Space for BoxSpace<T> {
     type Item = Vec<T>;
 
-    fn new(val: &Value) -> Self {
-        let info = val["info"].as_object().unwrap();
-        let name = info["name"].as_str().unwrap();
-        if name != "Box" {
-            panic!("name must be Box for Box spaces.")
-        }
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "Box")?;
 
-        Self {
-            shape: array_from_value::<usize>(&info["shape"]),
-            high: array_from_value::<T>(&info["high"]),
-            low: array_from_value::<T>(&info["low"]),
-        }
+        Ok(Self {
+            shape: array_from_value::<usize>(&info["shape"])?,
+            high: bounds_from_value::<T>(&info["high"])?,
+            low: bounds_from_value::<T>(&info["low"])?,
+        })
     }
 
-    fn action(val: &Value) -> Vec<T> {
+    fn action(&self, val: &Value) -> GymResult<Vec<T>> {
         array_from_value::<T>(val)
     }
 
-    fn observation(val: &Value) -> Vec<T> {
-        let ty = val["type"].as_str().unwrap();
-        let data = val["data"].as_str().unwrap();
+    fn observation(&self, val: &WireValue) -> GymResult<Vec<T>> {
+        let ty = field(val.value, "type", Value::as_str)?;
+        let data = &val.get("data");
 
-        deserialize_binary_stream::<T>(ty, data)
+        deserialize_binary_stream_value::<T>(ty, data)
     }
 
-    fn action_request(action: &Self::Item) -> HashMap<&str, Value> {
+    fn action_request(&self, action: &Self::Item) -> HashMap<&str, Value> {
         let action: Vec<_> = action.iter().collect();
         let mut req = HashMap::from([]);
         req.insert("action", to_value(action).unwrap());
 
         req
     }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Vec<T>> {
+        Ok(self
+            .low
+            .iter()
+            .zip(&self.high)
+            .map(|(&l, &h)| T::sample(l, h, rng))
+            .collect())
+    }
+
+    fn contains(&self, item: &Vec<T>) -> bool {
+        item.len() == self.low.len()
+            && itertools::izip!(item, &self.low, &self.high).all(|(x, l, h)| l <= x && x <= h)
+    }
+}
+
+/// Refer: https://gymnasium.farama.org/api/spaces/fundamental/#gymnasium.spaces.MultiDiscrete
+#[derive(Clone, Debug)]
+pub struct MultiDiscreteSpace {
+    pub shape: Vec<usize>,
+    pub nvec: Vec<Discrete>,
+    pub start: Vec<Discrete>,
+}
+
+impl Space for MultiDiscreteSpace {
+    type Item = Vec<Discrete>;
+
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "MultiDiscrete")?;
+        let nvec = array_from_value::<Discrete>(&info["nvec"])?;
+        let start = match info.get("start") {
+            Some(start) => array_from_value::<Discrete>(start)?,
+            None => vec![0; nvec.len()],
+        };
+
+        Ok(Self {
+            shape: array_from_value::<usize>(&info["shape"])?,
+            nvec,
+            start,
+        })
+    }
+
+    fn action(&self, val: &Value) -> GymResult<Vec<Discrete>> {
+        array_from_value::<Discrete>(val)
+    }
+
+    fn observation(&self, val: &WireValue) -> GymResult<Vec<Discrete>> {
+        let ty = field(val.value, "type", Value::as_str)?;
+        let data = &val.get("data");
+
+        deserialize_binary_stream_value::<Discrete>(ty, data)
+    }
+
+    fn action_request(&self, action: &Self::Item) -> HashMap<&str, Value> {
+        let mut req = HashMap::from([]);
+        req.insert("action", to_value(action).unwrap());
+
+        req
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Vec<Discrete>> {
+        Ok(self
+            .nvec
+            .iter()
+            .zip(&self.start)
+            .map(|(&n, &s)| s + rng.gen_range(0..n))
+            .collect())
+    }
+
+    fn contains(&self, item: &Vec<Discrete>) -> bool {
+        item.len() == self.nvec.len()
+            && itertools::izip!(item, &self.nvec, &self.start)
+                .all(|(&x, &n, &s)| s <= x && x < s + n)
+    }
+}
+
+/// Refer: https://gymnasium.farama.org/api/spaces/fundamental/#gymnasium.spaces.MultiBinary
+#[derive(Clone, Debug)]
+pub struct MultiBinarySpace {
+    pub shape: Vec<usize>,
+}
+
+impl MultiBinarySpace {
+    /// Number of binary elements.
+    pub fn n(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+impl Space for MultiBinarySpace {
+    type Item = Vec<Discrete>;
+
+    fn new(val: &Value) -> GymResult<Self> {
+        let info = space_info(val, "MultiBinary")?;
+
+        Ok(Self {
+            shape: array_from_value::<usize>(&info["shape"])?,
+        })
+    }
+
+    fn action(&self, val: &Value) -> GymResult<Vec<Discrete>> {
+        array_from_value::<Discrete>(val)
+    }
+
+    fn observation(&self, val: &WireValue) -> GymResult<Vec<Discrete>> {
+        let ty = field(val.value, "type", Value::as_str)?;
+        let data = &val.get("data");
+
+        deserialize_binary_stream_value::<Discrete>(ty, data)
+    }
+
+    fn action_request(&self, action: &Self::Item) -> HashMap<&str, Value> {
+        let mut req = HashMap::from([]);
+        req.insert("action", to_value(action).unwrap());
+
+        req
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> GymResult<Vec<Discrete>> {
+        Ok((0..self.n()).map(|_| rng.gen_range(0..=1)).collect())
+    }
+
+    fn contains(&self, item: &Vec<Discrete>) -> bool {
+        item.len() == self.n() && item.iter().all(|&x| x == 0 || x == 1)
+    }
 }
 
 #[derive(Debug)]
@@ -145,10 +464,73 @@ pub struct StepInfo<O: Space> {
     pub truncated: bool,
     pub terminated: bool,
     pub info: Value,
+    /// Observation and info the episode ended with, when the environment was auto reset in the same step.
+    /// Only set by VecEnvironment, observation and info are then those of the reset.
+    pub final_observation: Option<O::Item>,
+    pub final_info: Option<Value>,
+}
+
+impl<O: Space> StepInfo<O> {
+    /// Info decoded into T, e.g. info::TaxiInfo.
+    pub fn info_as<T: DeserializeOwned>(&self) -> GymResult<T> {
+        Ok(T::deserialize(&self.info)?)
+    }
+
+    fn from_value(obs_space: &O, body: &Body) -> GymResult<Self> {
+        let obj = &body.value;
+        Ok(Self {
+            observation: obs_space.observation(&body.get("observation"))?,
+            reward: field(obj, "reward", Value::as_f64)?,
+            truncated: field(obj, "truncated", Value::as_bool)?,
+            terminated: field(obj, "terminated", Value::as_bool)?,
+            info: body.get("info").to_value(),
+            final_observation: None,
+            final_info: None,
+        })
+    }
+}
+
+/// Initial observation of an episode, and the info it came with e.g. action masks or start state metadata.
+/// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.reset
+#[derive(Debug)]
+pub struct ResetInfo<O: Space> {
+    pub observation: O::Item,
+    pub info: Value,
+}
+
+impl<O: Space> ResetInfo<O> {
+    /// Refer: StepInfo::info_as.
+    pub fn info_as<T: DeserializeOwned>(&self) -> GymResult<T> {
+        Ok(T::deserialize(&self.info)?)
+    }
+
+    fn from_value(obs_space: &O, body: &Body) -> GymResult<Self> {
+        Ok(Self {
+            observation: obs_space.observation(&body.get("observation"))?,
+            // NOTE: Servers predating reset info send none.
+            info: match body.get("info").to_value() {
+                Value::Null => Value::Object(Map::new()),
+                info => info,
+            },
+        })
+    }
+}
+
+fn reset_request(seed: Option<u64>, options: Option<&Map<String, Value>>) -> HashMap<&str, Value> {
+    let mut body = HashMap::new();
+    if let Some(seed) = seed {
+        let _ = body.insert("seed", Value::from(seed));
+    }
+    if let Some(options) = options {
+        let _ = body.insert("options", Value::Object(options.clone()));
+    }
+
+    body
 }
 
 /// Create a gymnasium environment or get reference to an existing one.
-/// NOTE: All APIs are sync for now as the server is expected to be local.
+/// NOTE: Blocking APIs, as the server is expected to be local. Refer: AsyncEnvironment for the async ones.
+/// NOTE: Server instances are not closed on drop unless opted in via close_on_drop.
 #[derive(Debug)]
 pub struct Environment<O: Space, A: Space> {
     client: Client,
@@ -156,23 +538,19 @@ pub struct Environment<O: Space, A: Space> {
     instance_id: String,
     obs_space: O,
     act_space: A,
+    owned: bool,
+    close_on_drop: bool,
+    closed: Cell<bool>,
 }
 
 impl<O: Space, A: Space> Environment<O, A> {
-    pub fn envs(api_url: &str) -> HashMap<String, String> {
-        let client = Client::new(api_url);
+    pub fn envs(api_url: &str) -> GymResult<HashMap<String, String>> {
+        let client = Client::new(api_url)?;
 
         let url = client.make_api_url("");
-        let val = client.http_get(&url);
+        let val = client.http_get(&url)?.value;
 
-        let obj = val["all_envs"]
-            .as_object()
-            .ok_or("No all_envs returned.")
-            .unwrap();
-
-        obj.into_iter()
-            .map(|(k, v)| (k.clone(), v.as_str().unwrap().to_string()))
-            .collect()
+        envs_from_value(&val)
     }
 
     pub fn rc(self) -> Rc<Self> {
@@ -186,66 +564,85 @@ impl<O: Space, A: Space> Environment<O, A> {
         auto_reset: Option<bool>,
         disable_env_checker: Option<bool>,
         kwargs: &[(&str, Value)],
-    ) -> Self {
-        let mut body = [("env_id", to_value(env_name).unwrap())]
-            .into_iter()
-            .collect::<HashMap<&str, Value>>();
-
-        if let Some(max_episode_steps) = max_episode_steps {
-            body.insert("max_episode_steps", to_value(max_episode_steps).unwrap());
-        }
-
-        if let Some(auto_reset) = auto_reset {
-            body.insert("auto_reset", to_value(auto_reset).unwrap());
-        }
-
-        if let Some(disable_env_checker) = disable_env_checker {
-            body.insert(
-                "disable_env_checker",
-                to_value(disable_env_checker).unwrap(),
-            );
-        }
-
-        let kwargs = kwargs.iter().cloned().collect::<HashMap<&str, Value>>();
-        body.insert("kwargs", to_value(kwargs).unwrap());
-
-        let c = Client::new(api_url);
+    ) -> GymResult<Self> {
+        let body = make_env_request(
+            env_name,
+            max_episode_steps,
+            auto_reset,
+            disable_env_checker,
+            kwargs,
+        )?;
+
+        let c = Client::new(api_url)?;
         let base_url = c.make_api_url("");
-        let obj = c.http_post(&base_url, &body);
-        let inst_id = obj["instance_id"].as_str().unwrap();
-
-        Self::reference(api_url, inst_id)
+        let obj = c.http_post(&base_url, &body)?.value;
+        let inst_id = field(&obj, "instance_id", Value::as_str)?;
+
+        let mut env = match Self::reference(api_url, inst_id) {
+            Ok(env) => env,
+            Err(e) => {
+                // NOTE: Nobody else knows of the instance, the creation error is the one worth reporting.
+                let _ = c.http_delete(&c.make_api_url(&format!("{inst_id}/")));
+                return Err(e);
+            }
+        };
+        env.owned = true;
+
+        Ok(env)
     }
 
-    pub fn reference(api_url: &str, instance_id: &str) -> Self {
-        let client = Client::new(api_url);
+    pub fn reference(api_url: &str, instance_id: &str) -> GymResult<Self> {
+        let client = Client::new(api_url)?;
 
         let url = client.make_api_url(&format!("{}/observation_space/", instance_id));
-        let obj = client.http_get(&url);
-        let obs_space = O::new(&obj);
+        let obj = client.http_get(&url)?.value;
+        let obs_space = O::new(&obj)?;
 
         let url = client.make_api_url(&format!("{}/action_space/", instance_id));
-        let obj = client.http_get(&url);
-        let act_space = A::new(&obj);
+        let obj = client.http_get(&url)?.value;
+        let act_space = A::new(&obj)?;
 
         let env_api_url = client.make_api_url(&format!("{instance_id}/"));
-        Self {
+        Ok(Self {
             client,
             api_url: env_api_url,
             instance_id: instance_id.to_string(),
             obs_space,
             act_space,
+            owned: false,
+            close_on_drop: false,
+            closed: Cell::new(false),
+        })
+    }
+
+    /// Close the server instance when this Environment is dropped.
+    /// Only takes effect for instances created via Environment::new, references never own the instance.
+    pub fn close_on_drop(mut self) -> Self {
+        self.close_on_drop = self.owned;
+        self
+    }
+
+    /// Close the server instance. Any further call on this Environment will fail.
+    /// Refer: https://gymnasium.farama.org/api/env/#gymnasium.Env.close
+    pub fn close(&self) -> GymResult<()> {
+        if self.closed.get() {
+            return Ok(());
         }
+
+        self.client.http_delete(&self.api_url)?;
+        self.closed.set(true);
+
+        Ok(())
     }
 
     pub fn client_base_url(&self) -> &str {
         &self.client.base_url
     }
 
-    pub fn name(&self) -> String {
-        let obj = self.client.http_get(&self.api_url);
+    pub fn name(&self) -> GymResult<String> {
+        let obj = self.client.http_get(&self.api_url)?.value;
 
-        obj["id"].as_str().unwrap().to_string()
+        Ok(field(&obj, "id", Value::as_str)?.to_string())
     }
 
     pub fn instance_id(&self) -> &str {
@@ -268,56 +665,58 @@ impl<O: Space, A: Space> Environment<O, A> {
         &self.obs_space
     }
 
-    pub fn action_space_sample(&self) -> A::Item {
+    pub fn action_space_sample(&self) -> GymResult<A::Item> {
         let url = self.make_api_url("action_space/sample/");
-        let obj = self.client.http_get(&url);
-        A::action(&obj["action"])
+        let obj = self.client.http_get(&url)?.value;
+        self.act_space.action(&obj["action"])
     }
 
-    pub fn reset(&self, seed: Option<usize>) -> O::Item {
-        let mut body = HashMap::from([]);
-        if let Some(seed) = seed {
-            let _ = body.insert("seed", seed.to_string());
-        }
+    /// Server side membership check, i.e. by Gymnasium's action space rather than Space::contains.
+    /// Refer: https://gymnasium.farama.org/api/spaces/#gymnasium.spaces.Space.contains
+    pub fn action_space_contains(&self, action: &A::Item) -> GymResult<bool> {
+        let req = self.act_space.action_request(action);
+
+        let url = self.make_api_url(&format!("action_space/contains/{}/", req["action"]));
+        let obj = self.client.http_get(&url)?.value;
+        field(&obj, "member", Value::as_bool)
+    }
 
+    /// Whether the given properties match those of the server's observation space info, e.g.
+    /// &[("name", to_value("Discrete")?), ("n", to_value(16)?)]. Properties not passed are not compared.
+    pub fn observation_space_matches(&self, props: &[(&str, Value)]) -> GymResult<bool> {
+        let body: HashMap<&str, &Value> = props.iter().map(|(k, v)| (*k, v)).collect();
+
+        let url = self.make_api_url("observation_space/contains/");
+        let obj = self.client.http_post(&url, &body)?.value;
+        field(&obj, "member", Value::as_bool)
+    }
+
+    /// Options are passed on to the environment as is, e.g. {"low": -0.1, "high": 0.1} for CartPole.
+    pub fn reset(
+        &self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<O>> {
         let url = self.make_api_url("reset/");
-        let obj = self.client.http_post(&url, &body);
-        O::observation(&obj["observation"])
+        let obj = self.client.http_post(&url, &reset_request(seed, options))?;
+
+        ResetInfo::from_value(&self.obs_space, &obj)
     }
 
-    pub fn render(&self) -> RenderFrame {
+    pub fn render(&self) -> GymResult<RenderFrame> {
         let url = self.make_api_url("render/");
-        let obj = self.client.http_get(&url);
+        let obj = self.client.http_get(&url)?;
 
-        let rf = &obj["render_frame"];
-        if rf.is_string() {
-            RenderFrame::Ansi(rf.as_str().unwrap().to_string())
-        } else if rf.is_object() {
-            let obj = rf.as_object().unwrap();
-            let rows = obj["rows"].as_u64().unwrap() as usize;
-            let cols = obj["cols"].as_u64().unwrap() as usize;
-            let data = obj["data"].as_str().unwrap().to_string();
-
-            RenderFrame::Rgb(rows, cols, data)
-        } else {
-            unimplemented!()
-        }
+        RenderFrame::from_value(&obj.get("render_frame"))
     }
 
-    pub fn step(&self, action: &A::Item) -> StepInfo<O> {
-        let req = A::action_request(action);
+    pub fn step(&self, action: &A::Item) -> GymResult<StepInfo<O>> {
+        let req = self.act_space.action_request(action);
 
         let url = self.make_api_url("step/");
-        let obj = self.client.http_post(&url, &req);
-        let observation = O::observation(&obj["observation"]);
+        let obj = self.client.http_post(&url, &req)?;
 
-        StepInfo {
-            observation,
-            reward: obj["reward"].as_f64().unwrap(),
-            truncated: obj["truncated"].as_bool().unwrap(),
-            terminated: obj["terminated"].as_bool().unwrap(),
-            info: obj["info"].clone(),
-        }
+        StepInfo::from_value(&self.obs_space, &obj)
     }
 
     fn make_api_url(&self, path: &str) -> String {
@@ -325,55 +724,94 @@ impl<O: Space, A: Space> Environment<O, A> {
     }
 }
 
+impl<O: Space, A: Space> Drop for Environment<O, A> {
+    fn drop(&mut self) {
+        if self.close_on_drop {
+            // NOTE: Nothing to be done on failure, the server may already be gone.
+            let _ = self.close();
+        }
+    }
+}
+
+/// NOTE: Generated by the server, with random actions. Refer: PolicyEpisodeGenerator for any policy.
 impl<O, A> EpisodeGenerator<O> for Environment<O, A>
 where
     O: Space,
     for<'de> O: Deserialize<'de>,
     A: Space,
 {
-    fn generate(&self, count: usize, seed: Option<usize>) -> Vec<Vec<EpisodeEvent<O>>> {
+    fn generate(&self, count: usize, seed: Option<usize>) -> GymResult<Vec<Vec<EpisodeEvent<O>>>> {
         let mut body = HashMap::from([("count", count.to_string())]);
         if let Some(seed) = seed {
             let _ = body.insert("seed", seed.to_string());
         }
 
         let url = self.make_api_url("episodes/");
-        let obj = self.client.http_post(&url, &body);
-        serde_json::from_value::<Vec<Vec<EpisodeEvent<O>>>>(obj["episodes"].clone()).unwrap()
+        let obj = self.client.http_post(&url, &body)?.value;
+        Ok(serde_json::from_value::<Vec<Vec<EpisodeEvent<O>>>>(
+            obj["episodes"].clone(),
+        )?)
     }
 }
 
-pub fn transitions(env: &Environment<DiscreteSpace, DiscreteSpace>) -> Rc<Transitions> {
+/// Transition model of env, i.e. env.unwrapped.P, for any environment with finite spaces.
+/// NOTE: Unsupported for environments without one, e.g. CartPole, or whose spaces are not Discrete.
+pub fn transition_model<O: FiniteSpace, A: FiniteSpace>(
+    env: &Environment<O, A>,
+) -> GymResult<TransitionModel> {
+    sizes(env.observation_space(), env.action_space())?;
+
     let url = env.make_api_url("transitions/");
-    let obj = env.client.http_get(&url);
-    let obj = obj["transitions"].as_object().unwrap();
+    let obj = env.client.http_get(&url)?.value;
+    transition_model_from_value(&obj, env.observation_space(), env.action_space())
+}
 
-    let mut transitions: Transitions = HashMap::new();
+/// Sparse transitions of transition_model.
+pub fn transitions<O: FiniteSpace, A: FiniteSpace>(
+    env: &Environment<O, A>,
+) -> GymResult<Rc<Transitions>> {
+    transition_model(env).map(|m| Rc::new(m.into_transitions()))
+}
 
-    let n_s = env.observation_space().n;
-    let n_a = env.action_space().n;
-    for s in 0..n_s {
-        let s_trans = obj[&s.to_string()].as_object().unwrap();
-        for a in 0..n_a {
-            let a_trans = s_trans[&a.to_string()].as_array().unwrap();
-            let ts = a_trans
-                .iter()
-                .map(|t| {
-                    let t = t.as_array().unwrap();
-                    Transition {
-                        probability: Continous::from_value(&t[0]).unwrap(),
-                        next_state: Discrete::from_value(&t[1]).unwrap(),
-                        reward: Continous::from_value(&t[2]).unwrap(),
-                        done: t[3].as_bool().unwrap(),
-                    }
-                })
-                .collect();
+fn envs_from_value(val: &Value) -> GymResult<HashMap<String, String>> {
+    let obj = field(val, "all_envs", Value::as_object)?;
 
-            transitions.insert((s, a), ts);
-        }
+    obj.into_iter()
+        .map(|(k, v)| {
+            v.as_str()
+                .map(|v| (k.clone(), v.to_string()))
+                .ok_or_else(|| GymError::MalformedJson(format!("invalid env id for {k}")))
+        })
+        .collect()
+}
+
+fn make_env_request<'a>(
+    env_name: &str,
+    max_episode_steps: Option<Discrete>,
+    auto_reset: Option<bool>,
+    disable_env_checker: Option<bool>,
+    kwargs: &[(&'a str, Value)],
+) -> GymResult<HashMap<&'a str, Value>> {
+    let mut body = [("env_id", to_value(env_name)?)]
+        .into_iter()
+        .collect::<HashMap<&str, Value>>();
+
+    if let Some(max_episode_steps) = max_episode_steps {
+        body.insert("max_episode_steps", to_value(max_episode_steps)?);
+    }
+
+    if let Some(auto_reset) = auto_reset {
+        body.insert("auto_reset", to_value(auto_reset)?);
+    }
+
+    if let Some(disable_env_checker) = disable_env_checker {
+        body.insert("disable_env_checker", to_value(disable_env_checker)?);
     }
 
-    Rc::new(transitions)
+    let kwargs = kwargs.iter().cloned().collect::<HashMap<&str, Value>>();
+    body.insert("kwargs", to_value(kwargs)?);
+
+    Ok(body)
 }
 
 pub struct RandomEnvironmentPolicy<O: Space, A: Space> {
@@ -381,11 +819,33 @@ pub struct RandomEnvironmentPolicy<O: Space, A: Space> {
 }
 
 impl<O: Space, A: Space> Policy<O, A> for RandomEnvironmentPolicy<O, A> {
-    fn policy(&self, _s: &O::Item) -> A::Item {
+    fn policy(&self, _s: &O::Item) -> GymResult<A::Item> {
         self.env.action_space_sample()
     }
 }
 
+/// Random policy sampling the action space locally from a seeded RNG, i.e. reproducible and without
+/// the extra server round-trip per step of RandomEnvironmentPolicy.
+pub struct SeededRandomEnvironmentPolicy<O: Space, A: Space> {
+    pub env: Rc<Environment<O, A>>,
+    rng: RefCell<StdRng>,
+}
+
+impl<O: Space, A: Space> SeededRandomEnvironmentPolicy<O, A> {
+    pub fn new(env: Rc<Environment<O, A>>, seed: u64) -> Self {
+        Self {
+            env,
+            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+}
+
+impl<O: Space, A: Space> Policy<O, A> for SeededRandomEnvironmentPolicy<O, A> {
+    fn policy(&self, _s: &O::Item) -> GymResult<A::Item> {
+        self.env.action_space().sample(&mut *self.rng.borrow_mut())
+    }
+}
+
 #[derive(Debug)]
 pub struct Client {
     base_url: String,
@@ -393,21 +853,16 @@ pub struct Client {
     client: reqwest::blocking::Client,
 }
 
-/// NOTE: Retaining sync implementations now as the scenario is only single threaded.
+/// NOTE: Blocking implementation for the single threaded scenario. Refer: AsyncClient.
 impl Client {
-    pub fn new(base_url: &str) -> Self {
-        let mut base_url = base_url.replace("//localhost:", "//127.0.0.1:");
-        if base_url.ends_with('/') {
-            _ = base_url.remove(base_url.len() - 1);
-        }
+    pub fn new(base_url: &str) -> GymResult<Self> {
+        let (base_url, api_url) = Self::make_urls(base_url);
 
-        let api_url = format!("{base_url}/v1/envs/");
-
-        Self {
+        Ok(Self {
             base_url,
             api_url,
-            client: reqwest::blocking::Client::builder().build().unwrap(),
-        }
+            client: reqwest::blocking::Client::builder().build()?,
+        })
     }
 
     pub fn make_api_url(&self, path: &str) -> String {
@@ -418,44 +873,181 @@ impl Client {
         &self.base_url
     }
 
-    fn http_get(&self, url: &str) -> Value {
+    fn make_urls(base_url: &str) -> (String, String) {
+        let mut base_url = base_url.replace("//localhost:", "//127.0.0.1:");
+        if base_url.ends_with('/') {
+            _ = base_url.remove(base_url.len() - 1);
+        }
+
+        let api_url = format!("{base_url}/v1/envs/");
+
+        (base_url, api_url)
+    }
+
+    fn http_get(&self, url: &str) -> GymResult<Body> {
         let res = self
             .client
             .get(url)
             .headers(Self::construct_common_headers())
-            .send();
-        res.unwrap().json::<Value>().unwrap()
+            .send()?;
+        Self::parse_response(res)
     }
 
-    fn http_post<T: Serialize>(&self, url: &str, body: &HashMap<&str, T>) -> Value {
+    fn http_post<T: Serialize>(&self, url: &str, body: &HashMap<&str, T>) -> GymResult<Body> {
         let res = self
             .client
             .post(url)
             .headers(Self::construct_common_headers())
             .json(body)
-            .send();
-        res.unwrap().json::<Value>().unwrap()
+            .send()?;
+        Self::parse_response(res)
     }
 
+    fn http_delete(&self, url: &str) -> GymResult<()> {
+        let res = self
+            .client
+            .delete(url)
+            .headers(Self::construct_common_headers())
+            .send()?;
+        let status = res.status();
+        if !status.is_success() {
+            let format = Self::wire_format(res.headers());
+            return Err(Self::http_error(status, format, &res.bytes()?));
+        }
+
+        Ok(())
+    }
+
+    fn parse_response(res: Response) -> GymResult<Body> {
+        let status = res.status();
+        let format = Self::wire_format(res.headers());
+        let body = res.bytes()?;
+        Self::parse_body(status, format, &body)
+    }
+
+    fn parse_body(status: StatusCode, format: WireFormat, body: &[u8]) -> GymResult<Body> {
+        if !status.is_success() {
+            return Err(Self::http_error(status, format, body));
+        }
+
+        format.decode(body)
+    }
+
+    /// Server errors (InvalidUsage) carry the reason in the 'message' field of the body.
+    fn http_error(status: StatusCode, format: WireFormat, body: &[u8]) -> GymError {
+        let message = format
+            .decode(body)
+            .ok()
+            .and_then(|b| b.value["message"].as_str().map(str::to_string))
+            .unwrap_or_else(|| String::from_utf8_lossy(body).into_owned());
+
+        // NOTE: How the servers report what the environment does not support, e.g. no transition model.
+        if status == StatusCode::NOT_IMPLEMENTED {
+            return GymError::Unsupported(message);
+        }
+
+        GymError::Http {
+            status: status.as_u16(),
+            message,
+        }
+    }
+
+    /// Format of the response body, as negotiated via the Accept header. Refer: WireFormat.
+    fn wire_format(headers: &HeaderMap) -> WireFormat {
+        WireFormat::from_content_type(headers.get(CONTENT_TYPE).and_then(|ct| ct.to_str().ok()))
+    }
+
+    /// NOTE: Request bodies are always JSON, only the responses are negotiated.
     fn construct_common_headers() -> HeaderMap {
         let mut headers = HeaderMap::new();
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert(ACCEPT, HeaderValue::from_static(WireFormat::ACCEPT));
         headers
     }
 }
 
+/// Box-Muller transform.
+fn sample_standard_normal<R: Rng + ?Sized>(rng: &mut R) -> Continous {
+    let u1 = 1. - rng.gen::<Continous>();
+    let u2 = rng.gen::<Continous>();
+    (-2. * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+fn sample_exponential<R: Rng + ?Sized>(rng: &mut R) -> Continous {
+    -(1. - rng.gen::<Continous>()).ln()
+}
+
 mod value_extensions {
     use super::*;
     use serde_json::Value;
 
     impl FromCustom for Discrete {
+        fn from_value(val: &Value) -> Option<Self> {
+            val.as_i64()
+        }
+
+        fn from_i64(x: i64) -> Option<Self> {
+            Some(x)
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            integral(x)
+        }
+    }
+
+    impl FromCustom for i32 {
+        fn from_value(val: &Value) -> Option<Self> {
+            val.as_i64()?.try_into().ok()
+        }
+
+        fn from_i64(x: i64) -> Option<Self> {
+            x.try_into().ok()
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            integral(x)
+        }
+    }
+
+    impl FromCustom for i8 {
+        fn from_value(val: &Value) -> Option<Self> {
+            val.as_i64()?.try_into().ok()
+        }
+
+        fn from_i64(x: i64) -> Option<Self> {
+            x.try_into().ok()
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            integral(x)
+        }
+    }
+
+    impl FromCustom for u8 {
+        fn from_value(val: &Value) -> Option<Self> {
+            val.as_u64()?.try_into().ok()
+        }
+
+        fn from_i64(x: i64) -> Option<Self> {
+            x.try_into().ok()
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            integral(x)
+        }
+    }
+
+    impl FromCustom for usize {
         fn from_value(val: &Value) -> Option<Self> {
             val.as_u64().map(|x| x as Self)
         }
 
-        fn from_le_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
-            let x = bytes.try_into()?;
-            Ok(Self::from_le_bytes(x))
+        fn from_i64(x: i64) -> Option<Self> {
+            x.try_into().ok()
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            integral(x)
         }
     }
 
@@ -464,28 +1056,70 @@ mod value_extensions {
             val.as_f64().map(|x| x as Self)
         }
 
-        fn from_le_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
-            let x = bytes.try_into()?;
-            Ok(Self::from_le_bytes(x))
+        fn from_i64(x: i64) -> Option<Self> {
+            let y = x as Self;
+            (y as i64 == x).then_some(y)
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            Some(x)
         }
     }
 
-    impl FromCustom for usize {
+    /// NOTE: Bounds are sent as float64, the extreme ones become infinite i.e. unbounded.
+    impl FromCustom for f32 {
         fn from_value(val: &Value) -> Option<Self> {
-            val.as_u64().map(|x| x as Self)
+            val.as_f64().map(|x| x as Self)
+        }
+
+        fn from_i64(x: i64) -> Option<Self> {
+            let y = x as Self;
+            (y as i64 == x).then_some(y)
+        }
+
+        fn from_f64(x: f64) -> Option<Self> {
+            let y = x as Self;
+            (y as f64 == x || x.is_nan()).then_some(y)
         }
+    }
 
-        fn from_le_bytes(bytes: &[u8]) -> Result<Self, Box<dyn Error>> {
-            let x = bytes.try_into()?;
-            Ok(Self::from_le_bytes(x))
+    fn integral<T: TryFrom<i64>>(x: f64) -> Option<T> {
+        if x.fract() != 0. || x < i64::MIN as f64 || x >= i64::MAX as f64 {
+            return None;
         }
+
+        (x as i64).try_into().ok()
     }
 
-    pub fn array_from_value<T: FromCustom>(val: &Value) -> Vec<T> {
+    pub fn array_from_value<T: FromCustom>(val: &Value) -> GymResult<Vec<T>> {
+        let invalid = || GymError::MalformedJson(format!("invalid array {val}"));
         val.as_array()
-            .unwrap()
+            .ok_or_else(invalid)?
             .iter()
-            .map(|x| T::from_value(x).unwrap())
+            .map(|x| T::from_value(x).ok_or_else(invalid))
             .collect()
     }
+
+    /// Extract val[key] with the given accessor, reporting a missing or mistyped field.
+    pub fn field<'a, T>(
+        val: &'a Value,
+        key: &str,
+        f: impl FnOnce(&'a Value) -> Option<T>,
+    ) -> GymResult<T> {
+        f(&val[key]).ok_or_else(|| GymError::MalformedJson(format!("missing or invalid '{key}'")))
+    }
+
+    /// The 'info' of a space, checked to be of the expected space name.
+    pub fn space_info<'a>(val: &'a Value, expected: &str) -> GymResult<&'a Value> {
+        let info = &val["info"];
+        let name = field(info, "name", Value::as_str)?;
+        if name != expected {
+            return Err(GymError::SpaceMismatch {
+                expected: expected.to_string(),
+                found: name.to_string(),
+            });
+        }
+
+        Ok(info)
+    }
 }
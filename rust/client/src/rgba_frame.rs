@@ -0,0 +1,106 @@
+use crate::common::{error::*, utils::*};
+use crate::*;
+use ndarray::Array3;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+/// Decoded rgb_array render frame, with the alpha channel added by the server.
+/// Pixels are row major, 4 bytes (r, g, b, a) each.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RgbaFrame {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl RgbaFrame {
+    pub const CHANNELS: usize = 4;
+
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> GymResult<Self> {
+        let expected = width as usize * height as usize * Self::CHANNELS;
+        if pixels.len() != expected {
+            return Err(GymError::MalformedJson(format!(
+                "expected {expected} bytes for a {width}x{height} rgba frame, got {}",
+                pixels.len()
+            )));
+        }
+
+        Ok(Self {
+            width,
+            height,
+            pixels,
+        })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// Of shape (rows, cols, 4), same as the rgb_array of gymnasium plus the alpha channel.
+    pub fn to_array(&self) -> Array3<u8> {
+        self.clone().into()
+    }
+
+    pub fn write_png<W: Write>(&self, w: W) -> GymResult<()> {
+        let mut enc = png::Encoder::new(w, self.width, self.height);
+        enc.set_color(png::ColorType::Rgba);
+        enc.set_depth(png::BitDepth::Eight);
+
+        let mut w = enc.write_header().map_err(io_error)?;
+        w.write_image_data(&self.pixels).map_err(io_error)?;
+        w.finish().map_err(io_error)
+    }
+
+    pub fn save_png<P: AsRef<Path>>(&self, path: P) -> GymResult<()> {
+        let file = File::create(path).map_err(GymError::Io)?;
+
+        self.write_png(BufWriter::new(file))
+    }
+}
+
+impl TryFrom<&RenderFrame> for RgbaFrame {
+    type Error = GymError;
+
+    fn try_from(rf: &RenderFrame) -> GymResult<Self> {
+        match rf {
+            RenderFrame::Rgb(rows, cols, data) => Self::new(
+                *cols as u32,
+                *rows as u32,
+                deserialize_binary_stream_to_bytes(data)?,
+            ),
+            RenderFrame::Rgba(frame) => Ok(frame.clone()),
+            RenderFrame::Ansi(_) => Err(GymError::Unsupported(
+                "render frame is not an rgb_array, i.e. ansi".to_string(),
+            )),
+        }
+    }
+}
+
+impl From<RgbaFrame> for Array3<u8> {
+    fn from(frame: RgbaFrame) -> Self {
+        let shape = (
+            frame.height as usize,
+            frame.width as usize,
+            RgbaFrame::CHANNELS,
+        );
+        // NOTE: Length is checked on construction.
+        Array3::from_shape_vec(shape, frame.pixels).unwrap()
+    }
+}
+
+fn io_error(e: png::EncodingError) -> GymError {
+    GymError::Io(e.into())
+}
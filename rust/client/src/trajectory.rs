@@ -0,0 +1,144 @@
+use crate::common::{defs::*, error::*};
+use crate::env::rollout_actions;
+use crate::*;
+use serde::de::DeserializeOwned;
+
+/// One step of an episode, i.e. taking a in s, receiving r and landing in s_next.
+#[derive(Clone, Debug)]
+pub struct TrajectoryStep<O: Space, A: Space> {
+    pub s: O::Item,
+    pub a: A::Item,
+    pub r: f64,
+    pub s_next: O::Item,
+    pub terminated: bool,
+    pub truncated: bool,
+    pub info: Value,
+}
+
+impl<O: Space, A: Space> TrajectoryStep<O, A> {
+    /// Refer: StepInfo::info_as.
+    pub fn info_as<T: DeserializeOwned>(&self) -> GymResult<T> {
+        Ok(T::deserialize(&self.info)?)
+    }
+}
+
+/// Steps of one episode, in order, along with the info of its reset.
+/// E.g. for control algorithms or off-policy estimators, which need the actions. Refer: episode_events for
+/// the (s, r) shape of EpisodeGenerator.
+pub struct Trajectory<O: Space, A: Space> {
+    pub reset_info: Value,
+    pub steps: Vec<TrajectoryStep<O, A>>,
+}
+
+impl<O: Space, A: Space> Trajectory<O, A> {
+    /// Run one episode of policy on env, same as rollout_with_limit.
+    pub fn record<E>(
+        env: &mut E,
+        policy: &dyn Policy<O, A>,
+        seed: Option<u64>,
+        max_episode_steps: Option<usize>,
+    ) -> GymResult<Self>
+    where
+        E: Env<O = O, A = A> + ?Sized,
+        O::Item: Clone,
+    {
+        let (ri, steps) = rollout_actions(env, policy, seed, max_episode_steps)?;
+
+        let mut s = ri.observation;
+        let steps = steps
+            .into_iter()
+            .map(|(a, si)| TrajectoryStep {
+                s: std::mem::replace(&mut s, si.observation.clone()),
+                a,
+                r: si.reward,
+                s_next: si.observation,
+                terminated: si.terminated,
+                truncated: si.truncated,
+                info: si.info,
+            })
+            .collect();
+
+        Ok(Self {
+            reset_info: ri.info,
+            steps,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, TrajectoryStep<O, A>> {
+        self.steps.iter()
+    }
+
+    /// Whether the episode ended in a terminal state, as opposed to being truncated or cut short.
+    pub fn is_terminated(&self) -> bool {
+        self.steps.last().is_some_and(|st| st.terminated)
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        self.steps.last().is_some_and(|st| st.truncated)
+    }
+
+    pub fn rewards(&self) -> impl Iterator<Item = f64> + '_ {
+        self.steps.iter().map(|st| st.r)
+    }
+
+    /// G_t = r_t + gamma * G_t+1 for each step t.
+    /// NOTE: The episode is not bootstrapped past its last step, even when truncated.
+    pub fn returns(&self, gamma: f64) -> Vec<f64> {
+        let mut g = 0.;
+        let mut returns = self
+            .steps
+            .iter()
+            .rev()
+            .map(|st| {
+                g = st.r + gamma * g;
+                g
+            })
+            .collect::<Vec<_>>();
+        returns.reverse();
+
+        returns
+    }
+
+    /// Return of the whole episode, i.e. G_0.
+    pub fn discounted_return(&self, gamma: f64) -> f64 {
+        self.steps.iter().rev().fold(0., |g, st| st.r + gamma * g)
+    }
+
+    /// Same shape as EpisodeGenerator::generate, i.e. the initial state with reward 0, then each next state
+    /// with the reward received on entering it.
+    pub fn episode_events<T>(&self) -> Vec<EpisodeEvent<T>>
+    where
+        O::Item: EpisodeState<T>,
+    {
+        let Some(first) = self.steps.first() else {
+            return vec![];
+        };
+
+        std::iter::once(EpisodeEvent {
+            s: first.s.to_state(),
+            r: 0.,
+        })
+        .chain(self.steps.iter().map(|st| EpisodeEvent {
+            s: st.s_next.to_state(),
+            r: st.r,
+        }))
+        .collect()
+    }
+}
+
+impl<'a, O: Space, A: Space> IntoIterator for &'a Trajectory<O, A> {
+    type Item = &'a TrajectoryStep<O, A>;
+    type IntoIter = std::slice::Iter<'a, TrajectoryStep<O, A>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.steps.iter()
+    }
+}
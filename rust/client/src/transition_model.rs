@@ -0,0 +1,338 @@
+use crate::common::wire_format::*;
+use crate::common::{defs::*, error::*};
+use crate::envs::NativeEnvironment;
+use crate::value_extensions::*;
+use crate::*;
+use ndarray::{Array2, Array3};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, to_value, Map};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Spaces of the items 0..n, i.e. the states and actions a transition model is indexed by.
+pub trait FiniteSpace: Space {
+    /// None if the space is not finite after all, e.g. an AnySpace discovered to be a Box.
+    fn n(&self) -> Option<Discrete>;
+}
+
+impl FiniteSpace for DiscreteSpace {
+    fn n(&self) -> Option<Discrete> {
+        Some(self.n)
+    }
+}
+
+impl FiniteSpace for AnySpace {
+    fn n(&self) -> Option<Discrete> {
+        self.as_discrete().map(|s| s.n)
+    }
+}
+
+/// Transitions of an environment, checked to cover every (s, a) of its spaces.
+/// Same as env.unwrapped.P of the toy text environments.
+/// Refer: https://gymnasium.farama.org/environments/toy_text/frozen_lake/
+/// NOTE: Serialized as a list of (s, a, transitions), as (s, a) keys are not valid JSON keys. Checked again
+/// on deserialization.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(try_from = "SerializedModel", into = "SerializedModel")]
+pub struct TransitionModel {
+    n_s: Discrete,
+    n_a: Discrete,
+    transitions: Transitions,
+}
+
+impl TransitionModel {
+    /// Fails unless there are transitions for every (s, a) in 0..n_s x 0..n_a and nothing else, with the
+    /// next states in 0..n_s and the probabilities in [0, 1].
+    pub fn new(n_s: Discrete, n_a: Discrete, transitions: Transitions) -> GymResult<Self> {
+        let invalid = |what: String| GymError::MalformedJson(format!("transition model: {what}"));
+
+        for s in 0..n_s {
+            for a in 0..n_a {
+                if !transitions.contains_key(&(s, a)) {
+                    return Err(invalid(format!("no transitions for ({s}, {a})")));
+                }
+            }
+        }
+
+        for (&(s, a), ts) in &transitions {
+            if !(0..n_s).contains(&s) || !(0..n_a).contains(&a) {
+                return Err(invalid(format!(
+                    "({s}, {a}) is out of {n_s} states x {n_a} actions"
+                )));
+            }
+            for t in ts {
+                if !(0..n_s).contains(&t.next_state) {
+                    return Err(invalid(format!(
+                        "next state {} of ({s}, {a}) is out of {n_s} states",
+                        t.next_state
+                    )));
+                }
+                if !(0. ..=1.).contains(&t.probability) {
+                    return Err(invalid(format!(
+                        "probability {} of ({s}, {a}) is out of [0, 1]",
+                        t.probability
+                    )));
+                }
+            }
+        }
+
+        Ok(Self {
+            n_s,
+            n_a,
+            transitions,
+        })
+    }
+
+    /// NOTE: Unsupported for environments without a transition model, e.g. CartPole.
+    pub fn from_native<E: NativeEnvironment>(env: &E) -> GymResult<Self>
+    where
+        E::O: FiniteSpace,
+        E::A: FiniteSpace,
+    {
+        let transitions = env.transitions().ok_or_else(|| {
+            GymError::Unsupported("environment has no transition model".to_string())
+        })?;
+        let (n_s, n_a) = sizes(env.observation_space(), env.action_space())?;
+
+        Self::new(n_s, n_a, transitions.clone())
+    }
+
+    pub fn n_s(&self) -> Discrete {
+        self.n_s
+    }
+
+    pub fn n_a(&self) -> Discrete {
+        self.n_a
+    }
+
+    pub fn transitions(&self) -> &Transitions {
+        &self.transitions
+    }
+
+    pub fn into_transitions(self) -> Transitions {
+        self.transitions
+    }
+
+    /// In either wire format, MessagePack being the compact one. Refer: decode.
+    pub fn encode(&self, format: WireFormat) -> Vec<u8> {
+        // NOTE: Serializing to a Value does not fail, there being no non-string keys.
+        format.encode(to_value(self).unwrap().into())
+    }
+
+    pub fn decode(format: WireFormat, data: &[u8]) -> GymResult<Self> {
+        Ok(serde_json::from_value(format.decode(data)?.value)?)
+    }
+
+    /// Dense P[s][a][s'], the probabilities of transitions to the same s' being summed.
+    pub fn p(&self) -> Array3<f64> {
+        let mut p = Array3::zeros((self.n_s as usize, self.n_a as usize, self.n_s as usize));
+        for (&(s, a), ts) in &self.transitions {
+            for t in ts {
+                p[[s as usize, a as usize, t.next_state as usize]] += t.probability;
+            }
+        }
+
+        p
+    }
+
+    /// Dense R[s][a], the expected reward of taking a in s.
+    pub fn r(&self) -> Array2<f64> {
+        let mut r = Array2::zeros((self.n_s as usize, self.n_a as usize));
+        for (&(s, a), ts) in &self.transitions {
+            r[[s as usize, a as usize]] = ts.iter().map(|t| t.probability * t.reward).sum();
+        }
+
+        r
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct SerializedModel {
+    n_s: Discrete,
+    n_a: Discrete,
+    transitions: Vec<(Discrete, Discrete, Vec<Transition>)>,
+}
+
+impl TryFrom<SerializedModel> for TransitionModel {
+    type Error = GymError;
+
+    fn try_from(m: SerializedModel) -> GymResult<Self> {
+        let transitions = m
+            .transitions
+            .into_iter()
+            .map(|(s, a, ts)| ((s, a), ts))
+            .collect();
+
+        Self::new(m.n_s, m.n_a, transitions)
+    }
+}
+
+impl From<TransitionModel> for SerializedModel {
+    /// Ordered by (s, a), i.e. the same model always serializes the same.
+    fn from(m: TransitionModel) -> Self {
+        let mut transitions = m
+            .transitions
+            .into_iter()
+            .map(|((s, a), ts)| (s, a, ts))
+            .collect::<Vec<_>>();
+        transitions.sort_by_key(|(s, a, _)| (*s, *a));
+
+        Self {
+            n_s: m.n_s,
+            n_a: m.n_a,
+            transitions,
+        }
+    }
+}
+
+/// Transition models on disk, keyed by env id and creation kwargs, e.g. to solve an MDP offline once its
+/// model was fetched. Stored as MessagePack, one file per model.
+#[derive(Clone, Debug)]
+pub struct TransitionCache {
+    dir: PathBuf,
+}
+
+impl TransitionCache {
+    /// NOTE: The directory is created on the first store.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// E.g. FrozenLake-v1-5f1d8a1c2b3e4f60.msgpack, the hash being that of the kwargs as sorted JSON.
+    pub fn path(&self, env_id: &str, kwargs: &[(&str, Value)]) -> PathBuf {
+        let kwargs = kwargs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.clone()))
+            .collect::<Map<_, _>>();
+        let key = json!({ "env_id": env_id, "kwargs": kwargs }).to_string();
+        let name = env_id
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>();
+
+        self.dir
+            .join(format!("{name}-{:016x}.msgpack", fnv1a(key.as_bytes())))
+    }
+
+    /// None if the model is not cached yet.
+    pub fn load(
+        &self,
+        env_id: &str,
+        kwargs: &[(&str, Value)],
+    ) -> GymResult<Option<TransitionModel>> {
+        match fs::read(self.path(env_id, kwargs)) {
+            Ok(data) => TransitionModel::decode(WireFormat::MessagePack, &data).map(Some),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(GymError::Io(e)),
+        }
+    }
+
+    /// NOTE: Written to a temporary file first, i.e. a concurrent load never sees a partial model.
+    pub fn store(
+        &self,
+        env_id: &str,
+        kwargs: &[(&str, Value)],
+        model: &TransitionModel,
+    ) -> GymResult<()> {
+        let path = self.path(env_id, kwargs);
+        let tmp = path.with_extension(format!("{}.tmp", std::process::id()));
+        fs::create_dir_all(&self.dir).map_err(GymError::Io)?;
+        fs::write(&tmp, model.encode(WireFormat::MessagePack)).map_err(GymError::Io)?;
+
+        fs::rename(&tmp, &path).map_err(GymError::Io)
+    }
+
+    /// The cached model, else the fetched one which is then cached e.g.
+    /// cache.get_or_fetch("FrozenLake-v1", &kwargs, || transition_model(&env)).
+    pub fn get_or_fetch(
+        &self,
+        env_id: &str,
+        kwargs: &[(&str, Value)],
+        fetch: impl FnOnce() -> GymResult<TransitionModel>,
+    ) -> GymResult<TransitionModel> {
+        if let Some(model) = self.load(env_id, kwargs)? {
+            return Ok(model);
+        }
+
+        let model = fetch()?;
+        self.store(env_id, kwargs, &model)?;
+
+        Ok(model)
+    }
+}
+
+/// 64-bit FNV-1a, i.e. stable across platforms and releases unlike std's DefaultHasher.
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf29ce484222325, |h, b| {
+        (h ^ u64::from(*b)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Number of states and actions, i.e. the sizes of the spaces.
+pub(crate) fn sizes<O: FiniteSpace, A: FiniteSpace>(
+    obs_space: &O,
+    act_space: &A,
+) -> GymResult<(Discrete, Discrete)> {
+    let not_finite = |what: &str| {
+        GymError::Unsupported(format!(
+            "{what} space is not Discrete, i.e. there is no transition model"
+        ))
+    };
+    let n_s = obs_space.n().ok_or_else(|| not_finite("observation"))?;
+    let n_a = act_space.n().ok_or_else(|| not_finite("action"))?;
+
+    Ok((n_s, n_a))
+}
+
+/// Same as env.unwrapped.P, i.e. { s: { a: [[probability, next_state, reward, done], ...] } }.
+pub(crate) fn transition_model_from_value<O: FiniteSpace, A: FiniteSpace>(
+    obj: &Value,
+    obs_space: &O,
+    act_space: &A,
+) -> GymResult<TransitionModel> {
+    let (n_s, n_a) = sizes(obs_space, act_space)?;
+    let obj = field(obj, "transitions", Value::as_object)?;
+
+    let key = |k: &str| {
+        k.parse::<Discrete>()
+            .map_err(|_| GymError::MalformedJson(format!("invalid state or action {k}")))
+    };
+    let mut transitions: Transitions = HashMap::new();
+    for (s, s_trans) in obj {
+        let s_trans = s_trans
+            .as_object()
+            .ok_or_else(|| GymError::MalformedJson(format!("invalid transitions for state {s}")))?;
+        for (a, a_trans) in s_trans {
+            let a_trans = a_trans.as_array().ok_or_else(|| {
+                GymError::MalformedJson(format!("invalid transitions for ({s}, {a})"))
+            })?;
+            let ts = a_trans
+                .iter()
+                .map(|t| {
+                    let t = t.as_array().filter(|t| t.len() == 4).ok_or_else(|| {
+                        GymError::MalformedJson(format!("invalid transition {t}"))
+                    })?;
+                    let invalid = || GymError::MalformedJson(format!("invalid transition {t:?}"));
+                    Ok(Transition {
+                        probability: Continous::from_value(&t[0]).ok_or_else(invalid)?,
+                        next_state: Discrete::from_value(&t[1]).ok_or_else(invalid)?,
+                        reward: Continous::from_value(&t[2]).ok_or_else(invalid)?,
+                        done: t[3].as_bool().ok_or_else(invalid)?,
+                    })
+                })
+                .collect::<GymResult<_>>()?;
+
+            transitions.insert((key(s)?, key(a)?), ts);
+        }
+    }
+
+    TransitionModel::new(n_s, n_a, transitions)
+}
@@ -1,26 +1,63 @@
-use crate::common::defs::*;
+use crate::common::{defs::*, error::*};
+use crate::{Env, Environment};
 use display::*;
 use iced::executor;
 use iced::theme::{self, Theme};
 use iced::time;
 use iced::widget::{button, column, container, row, slider, text};
 use iced::{Alignment, Application, Command, Element, Length, Settings, Subscription};
-use std::marker::PhantomData;
+use serde_json::{Map, Value};
+use std::fmt;
 use std::rc::Rc;
 use std::time::Duration;
 
-pub type Result = iced::Result;
+pub type Result = std::result::Result<(), Error>;
+
+/// Why the ui could not be run, the environment could not be referenced or reset before it started, or iced failed.
+#[derive(Debug)]
+pub enum Error {
+    Gym(GymError),
+    Iced(iced::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Gym(e) => write!(f, "{e}"),
+            Error::Iced(e) => write!(f, "ui error: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Gym(e) => Some(e),
+            Error::Iced(e) => Some(e),
+        }
+    }
+}
+
+impl From<GymError> for Error {
+    fn from(e: GymError) -> Self {
+        Error::Gym(e)
+    }
+}
+
+impl From<iced::Error> for Error {
+    fn from(e: iced::Error) -> Self {
+        Error::Iced(e)
+    }
+}
 
 /// TODO: There are 2 calls to render coming from here.
-pub struct GymnasiumApp<O: crate::Space + 'static, A: crate::Space + 'static> {
-    display: Display<O, A>,
+pub struct GymnasiumApp<E: Env + 'static> {
+    display: Display<E>,
     is_playing: bool,
     queued_ticks: usize,
     speed: usize,
     next_speed: Option<usize>,
     version: usize,
-    p_o: PhantomData<O>,
-    p_a: PhantomData<A>,
 }
 
 #[derive(Debug, Clone)]
@@ -33,23 +70,21 @@ pub enum Message {
     Reset,
 }
 
-impl<O: crate::Space, A: crate::Space> Application for GymnasiumApp<O, A> {
+impl<E: Env + 'static> Application for GymnasiumApp<E> {
     type Message = Message;
     type Theme = Theme;
     type Executor = executor::Default;
-    type Flags = EnvironmentProxyFlags<O, A>;
+    type Flags = EnvironmentProxy<E>;
 
-    fn new(flags: EnvironmentProxyFlags<O, A>) -> (Self, Command<Message>) {
+    fn new(env: EnvironmentProxy<E>) -> (Self, Command<Message>) {
         (
             Self {
-                display: Display::new(flags),
+                display: Display::new(env),
                 is_playing: Default::default(),
                 queued_ticks: Default::default(),
                 speed: 30,
                 next_speed: Default::default(),
                 version: Default::default(),
-                p_o: PhantomData,
-                p_a: PhantomData,
             },
             Command::none(),
         )
@@ -109,7 +144,7 @@ impl<O: crate::Space, A: crate::Space> Application for GymnasiumApp<O, A> {
         }
     }
 
-    fn view(&self) -> Element<Message> {
+    fn view(&self) -> Element<'_, Message> {
         let version = self.version;
         let selected_speed = self.next_speed.unwrap_or(self.speed);
         let frame_rate = 0.0;
@@ -134,13 +169,37 @@ impl<O: crate::Space, A: crate::Space> Application for GymnasiumApp<O, A> {
     }
 }
 
-impl<O: crate::Space + 'static, A: crate::Space + 'static> GymnasiumApp<O, A> {
+impl<O: crate::Space + 'static, A: crate::Space + 'static> GymnasiumApp<Environment<O, A>> {
+    /// Run against an instance served at api_url. Refer: run_env for any Env, e.g. a LocalEnvironment.
     pub fn run(
         api_url: &str,
         instance_id: &str,
-        reset_seed: Option<usize>,
+        reset_seed: Option<u64>,
+        reset_options: Option<Map<String, Value>>,
         policy: Rc<dyn Policy<O, A>>,
-    ) -> iced::Result {
+    ) -> Result {
+        let env = Environment::reference(api_url, instance_id)?;
+
+        Self::run_env(env, reset_seed, reset_options, policy)
+    }
+}
+
+impl<E: Env + 'static> GymnasiumApp<E> {
+    /// NOTE: Frames are shown only for environments rendering in rgb_array mode.
+    /// The environment is reset before the window opens, failing that the error is returned.
+    pub fn run_env(
+        env: E,
+        reset_seed: Option<u64>,
+        reset_options: Option<Map<String, Value>>,
+        policy: Rc<dyn Policy<E::O, E::A>>,
+    ) -> Result {
+        let env = EnvironmentProxy::new(EnvironmentProxyFlags {
+            env,
+            reset_seed,
+            reset_options,
+            policy,
+        })?;
+
         <Self as Application>::run(Settings {
             antialiasing: true,
             window: iced::window::Settings {
@@ -152,13 +211,10 @@ impl<O: crate::Space + 'static, A: crate::Space + 'static> GymnasiumApp<O, A> {
                 // TODO: icon.
                 ..iced::window::Settings::default()
             },
-            ..Settings::with_flags(EnvironmentProxyFlags {
-                api_url: api_url.to_string(),
-                instance_id: instance_id.to_string(),
-                reset_seed,
-                policy,
-            })
-        })
+            ..Settings::with_flags(env)
+        })?;
+
+        Ok(())
     }
 
     fn view_controls<'a>(is_playing: bool, speed: usize, frame_rate: f32) -> Element<'a, Message> {
@@ -193,15 +249,17 @@ impl<O: crate::Space + 'static, A: crate::Space + 'static> GymnasiumApp<O, A> {
 }
 
 pub mod display {
-    use crate::common::{defs::*, utils::*};
-    use crate::{Environment, RenderFrame};
+    use crate::common::{defs::*, error::*};
+    use crate::{Env, RenderFrame, Space};
     use iced::{Element, Length};
+    use serde_json::{Map, Value};
+    use std::fmt;
     use std::future::Future;
     use std::rc::Rc;
     use std::time::{Duration, Instant};
 
-    pub struct Display<O: crate::Space, A: crate::Space> {
-        state: State<O, A>,
+    pub struct Display<E: Env> {
+        state: State<E>,
         last_tick_duration: Duration,
         last_queued_ticks: usize,
     }
@@ -217,12 +275,20 @@ pub mod display {
     #[derive(Debug, Clone)]
     pub enum TickError {
         JoinFailed,
+        StepFailed(String),
     }
 
-    impl<O: crate::Space, A: crate::Space> Display<O, A> {
-        pub fn new(flags: EnvironmentProxyFlags<O, A>) -> Self {
-            let env = EnvironmentProxy::new(flags);
+    impl fmt::Display for TickError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self {
+                TickError::JoinFailed => write!(f, "unable to join the tick task"),
+                TickError::StepFailed(what) => write!(f, "step failed: {what}"),
+            }
+        }
+    }
 
+    impl<E: Env> Display<E> {
+        pub fn new(env: EnvironmentProxy<E>) -> Self {
             Self {
                 state: State::with_env(env),
                 last_tick_duration: Duration::default(),
@@ -268,18 +334,24 @@ pub mod display {
                 Message::Ticked {
                     result: Err(error), ..
                 } => {
-                    dbg!(error);
+                    self.state.fail(error);
                 }
             }
         }
 
-        pub fn view(&self) -> Element<Message> {
-            let rgb = self.state.render_frame();
-            let rgb = rgb.as_rgb().unwrap();
-
-            let bytes = deserialize_binary_stream_to_bytes(rgb.2);
-            let handle =
-                iced::widget::image::Handle::from_pixels(*rgb.1 as u32, *rgb.0 as u32, bytes);
+        pub fn view(&self) -> Element<'_, Message> {
+            if let Some(e) = self.state.error() {
+                return Self::error_view(e.to_string());
+            }
+            let frame = match self.state.render_frame().and_then(|rf| rf.to_rgba()) {
+                Ok(x) => x,
+                Err(e) => return Self::error_view(e.to_string()),
+            };
+            let handle = iced::widget::image::Handle::from_pixels(
+                frame.width(),
+                frame.height(),
+                frame.into_pixels(),
+            );
             let image = iced::widget::Image::new(handle)
                 .width(Length::Fill)
                 .height(Length::Fill);
@@ -291,27 +363,44 @@ pub mod display {
                 .center_y()
                 .into()
         }
+
+        fn error_view<'a>(message: String) -> Element<'a, Message> {
+            iced::widget::container(iced::widget::text(message))
+                .width(Length::Fill)
+                .height(Length::Fill)
+                .center_x()
+                .center_y()
+                .into()
+        }
     }
 
-    struct State<O: crate::Space, A: crate::Space> {
-        env: EnvironmentProxy<O, A>,
+    struct State<E: Env> {
+        env: EnvironmentProxy<E>,
         is_ticking: bool,
+        /// NOTE: Set by a failed reset or step and shown instead of the frame until the next reset.
+        /// No ticks are taken while set.
+        error: Option<String>,
     }
 
-    impl<O: crate::Space, A: crate::Space> State<O, A> {
-        pub fn with_env(env: EnvironmentProxy<O, A>) -> Self {
+    impl<E: Env> State<E> {
+        pub fn with_env(env: EnvironmentProxy<E>) -> Self {
             Self {
                 env,
                 is_ticking: Default::default(),
+                error: None,
             }
         }
 
-        fn render_frame(&self) -> RenderFrame {
+        fn render_frame(&self) -> GymResult<RenderFrame> {
             self.env.render_frame()
         }
 
+        fn error(&self) -> Option<&str> {
+            self.error.as_deref()
+        }
+
         pub fn reset(&mut self) {
-            self.env.reset();
+            self.error = self.env.reset().err().map(|e| e.to_string());
         }
 
         pub fn name(&self) -> &str {
@@ -322,68 +411,90 @@ pub mod display {
             self.is_ticking = false;
         }
 
+        fn fail(&mut self, error: TickError) {
+            self.is_ticking = false;
+            self.error = Some(error.to_string());
+        }
+
         fn tick(&mut self, amount: usize) -> Option<impl Future<Output = Result<(), TickError>>> {
-            if self.is_ticking {
+            if self.is_ticking || self.error.is_some() {
                 return None;
             }
 
             self.is_ticking = true;
 
-            for _ in 0..amount {
-                self.env.tick();
-            }
+            let ticked = (0..amount).try_for_each(|_| self.env.tick());
 
             Some(async move {
                 tokio::task::spawn_blocking(move || ())
                     .await
-                    .map_err(|_| TickError::JoinFailed)
+                    .map_err(|_| TickError::JoinFailed)?;
+                ticked.map_err(|e| TickError::StepFailed(e.to_string()))
             })
         }
     }
 
-    pub struct EnvironmentProxyFlags<O: crate::Space, A: crate::Space> {
-        pub api_url: String,
-        pub instance_id: String,
-        pub reset_seed: Option<usize>,
-        pub policy: Rc<dyn Policy<O, A>>,
+    pub struct EnvironmentProxyFlags<E: Env> {
+        pub env: E,
+        pub reset_seed: Option<u64>,
+        pub reset_options: Option<Map<String, Value>>,
+        pub policy: Rc<dyn Policy<E::O, E::A>>,
     }
 
-    pub struct EnvironmentProxy<O: crate::Space, A: crate::Space> {
-        env: Environment<O, A>,
+    pub struct EnvironmentProxy<E: Env> {
+        env: E,
         env_name: String,
-        reset_seed: Option<usize>,
-        last_known_state: O::Item,
-        policy: Rc<dyn Policy<O, A>>,
+        reset_seed: Option<u64>,
+        reset_options: Option<Map<String, Value>>,
+        last_known_state: <E::O as Space>::Item,
+        last_known_info: Value,
+        policy: Rc<dyn Policy<E::O, E::A>>,
     }
 
-    impl<O: crate::Space, A: crate::Space> EnvironmentProxy<O, A> {
-        pub fn new(flags: EnvironmentProxyFlags<O, A>) -> Self {
-            let env = Environment::reference(&flags.api_url, &flags.instance_id);
-            let last_known_state = env.reset(flags.reset_seed);
-            let env_name = env.name();
+    impl<E: Env> EnvironmentProxy<E> {
+        pub fn new(flags: EnvironmentProxyFlags<E>) -> GymResult<Self> {
+            let mut env = flags.env;
+            let ri = env.reset(flags.reset_seed, flags.reset_options.as_ref())?;
+            let env_name = env.name()?;
 
-            Self {
+            Ok(Self {
                 env,
                 env_name,
                 reset_seed: flags.reset_seed,
-                last_known_state,
+                reset_options: flags.reset_options,
+                last_known_state: ri.observation,
+                last_known_info: ri.info,
                 policy: flags.policy,
-            }
+            })
         }
 
-        pub fn tick(&mut self) {
-            let action = self.policy.policy(&self.last_known_state);
-            let si = self.env.step(&action);
+        pub fn tick(&mut self) -> GymResult<()> {
+            let action = self.policy.policy(&self.last_known_state)?;
+            let si = self.env.step(&action)?;
             self.last_known_state = si.observation;
+            self.last_known_info = si.info;
+
+            Ok(())
         }
 
         /// PERF: 2 of these are getting called for every step.
-        pub fn render_frame(&self) -> RenderFrame {
+        pub fn render_frame(&self) -> GymResult<RenderFrame> {
             self.env.render()
         }
 
-        pub fn reset(&mut self) {
-            self.last_known_state = self.env.reset(self.reset_seed);
+        pub fn reset(&mut self) -> GymResult<()> {
+            let ri = self
+                .env
+                .reset(self.reset_seed, self.reset_options.as_ref())?;
+            self.last_known_state = ri.observation;
+            self.last_known_info = ri.info;
+
+            Ok(())
+        }
+
+        /// Info of the last reset or step, e.g. the action mask for the next step.
+        pub fn info(&self) -> &Value {
+            &self.last_known_info
         }
 
         pub fn name(&self) -> &str {
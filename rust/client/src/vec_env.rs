@@ -0,0 +1,135 @@
+use crate::common::{defs::*, error::*};
+use crate::*;
+use serde_json::{Map, Value};
+
+/// N server instances of the same environment, stepped together.
+/// Sub environments are auto reset in the same step they terminate or truncate, the observation and info
+/// the episode ended with are preserved in StepInfo::final_observation and StepInfo::final_info.
+/// Refer: https://gymnasium.farama.org/api/vector/#gymnasium.vector.VectorEnv
+/// NOTE: Sub environments are stepped one after the other, over the same blocking APIs as Environment.
+#[derive(Debug)]
+pub struct VecEnvironment<O: Space, A: Space> {
+    envs: Vec<Environment<O, A>>,
+}
+
+impl<O: Space, A: Space> VecEnvironment<O, A> {
+    pub fn new(
+        api_url: &str,
+        env_name: &str,
+        num_envs: usize,
+        max_episode_steps: Option<Discrete>,
+        disable_env_checker: Option<bool>,
+        kwargs: &[(&str, Value)],
+    ) -> GymResult<Self> {
+        if num_envs == 0 {
+            return Err(GymError::InvalidArgument(
+                "VecEnvironment needs at least one sub environment".to_string(),
+            ));
+        }
+
+        let mut envs = Vec::with_capacity(num_envs);
+        for _ in 0..num_envs {
+            match Environment::new(
+                api_url,
+                env_name,
+                max_episode_steps,
+                Some(false),
+                disable_env_checker,
+                kwargs,
+            ) {
+                Ok(env) => envs.push(env),
+                Err(e) => {
+                    // NOTE: Best effort, the creation error is the one worth reporting.
+                    for env in &envs {
+                        let _ = env.close();
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(Self { envs })
+    }
+
+    /// Refer: Environment::close_on_drop.
+    pub fn close_on_drop(self) -> Self {
+        Self {
+            envs: self.envs.into_iter().map(|e| e.close_on_drop()).collect(),
+        }
+    }
+
+    /// Close all the server instances.
+    pub fn close(&self) -> GymResult<()> {
+        self.envs.iter().try_for_each(Environment::close)
+    }
+
+    pub fn num_envs(&self) -> usize {
+        self.envs.len()
+    }
+
+    pub fn envs(&self) -> &[Environment<O, A>] {
+        &self.envs
+    }
+
+    /// Space of the actions of a single sub environment.
+    pub fn single_action_space(&self) -> &A {
+        self.envs[0].action_space()
+    }
+
+    /// Space of the observations of a single sub environment.
+    pub fn single_observation_space(&self) -> &O {
+        self.envs[0].observation_space()
+    }
+
+    /// Sub environment i is seeded with seeds[i], there must be one per sub environment. Options are passed to
+    /// all of them. Returns the observation along with the info of each, same as Environment::reset.
+    pub fn reset(
+        &self,
+        seeds: Option<&[u64]>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<Vec<ResetInfo<O>>> {
+        if let Some(seeds) = seeds.filter(|s| s.len() != self.envs.len()) {
+            return Err(GymError::InvalidArgument(format!(
+                "expected one seed per sub environment, {} for {}",
+                seeds.len(),
+                self.envs.len()
+            )));
+        }
+
+        self.envs
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.reset(seeds.map(|s| s[i]), options))
+            .collect()
+    }
+
+    pub fn step(&self, actions: &[A::Item]) -> GymResult<Vec<StepInfo<O>>> {
+        if actions.len() != self.envs.len() {
+            return Err(GymError::InvalidArgument(format!(
+                "expected one action per sub environment, {} for {}",
+                actions.len(),
+                self.envs.len()
+            )));
+        }
+
+        self.envs
+            .iter()
+            .zip(actions)
+            .map(|(e, a)| {
+                let si = e.step(a)?;
+                if !(si.terminated || si.truncated) {
+                    return Ok(si);
+                }
+
+                let ri = e.reset(None, None)?;
+                Ok(StepInfo {
+                    observation: ri.observation,
+                    info: ri.info,
+                    final_observation: Some(si.observation),
+                    final_info: Some(si.info),
+                    ..si
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,748 @@
+//! Wrappers around any Env, themselves an Env, i.e. they compose e.g.
+//! RecordEpisodeStatistics::new(TimeLimit::new(env, 200), None).
+//! Refer: https://gymnasium.farama.org/api/wrappers/
+
+use crate::common::{defs::*, error::*};
+use crate::info::EpisodeStatistics;
+use crate::*;
+use serde_json::{json, Map, Value};
+use std::collections::VecDeque;
+use std::time::Instant;
+
+fn map_reset<O: Space, O2: Space>(
+    ri: ResetInfo<O>,
+    f: impl FnOnce(O::Item) -> O2::Item,
+) -> ResetInfo<O2> {
+    ResetInfo {
+        observation: f(ri.observation),
+        info: ri.info,
+    }
+}
+
+fn map_observation<O: Space, O2: Space>(
+    si: StepInfo<O>,
+    mut f: impl FnMut(O::Item) -> O2::Item,
+) -> StepInfo<O2> {
+    StepInfo {
+        observation: f(si.observation),
+        reward: si.reward,
+        truncated: si.truncated,
+        terminated: si.terminated,
+        info: si.info,
+        final_observation: si.final_observation.map(f),
+        final_info: si.final_info,
+    }
+}
+
+/// Truncate episodes after max_episode_steps steps.
+/// Refer: https://gymnasium.farama.org/api/wrappers/misc_wrappers/#gymnasium.wrappers.TimeLimit
+#[derive(Debug)]
+pub struct TimeLimit<E: Env> {
+    env: E,
+    max_episode_steps: usize,
+    elapsed_steps: usize,
+}
+
+impl<E: Env> TimeLimit<E> {
+    pub fn new(env: E, max_episode_steps: usize) -> Self {
+        Self {
+            env,
+            max_episode_steps,
+            elapsed_steps: 0,
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E: Env> Env for TimeLimit<E> {
+    type O = E::O;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        self.elapsed_steps = 0;
+        self.env.reset(seed, options)
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<E::O>> {
+        let mut si = self.env.step(action)?;
+        self.elapsed_steps += 1;
+        if self.elapsed_steps >= self.max_episode_steps {
+            si.truncated = true;
+        }
+
+        Ok(si)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Add info["episode"] = {"r": return, "l": length, "t": elapsed seconds} when an episode ends,
+/// and keep the returns and lengths of the last deque_size (default 100) episodes.
+/// NOTE: Decoded via StepInfo::info_as::<info::EpisodeInfo>.
+/// Refer: https://gymnasium.farama.org/api/wrappers/misc_wrappers/#gymnasium.wrappers.RecordEpisodeStatistics
+#[derive(Debug)]
+pub struct RecordEpisodeStatistics<E: Env> {
+    env: E,
+    deque_size: usize,
+    episode_return: f64,
+    episode_length: usize,
+    episode_start: Instant,
+    return_queue: VecDeque<f64>,
+    length_queue: VecDeque<usize>,
+}
+
+impl<E: Env> RecordEpisodeStatistics<E> {
+    pub fn new(env: E, deque_size: Option<usize>) -> Self {
+        Self {
+            env,
+            deque_size: deque_size.unwrap_or(100),
+            episode_return: 0.,
+            episode_length: 0,
+            episode_start: Instant::now(),
+            return_queue: VecDeque::new(),
+            length_queue: VecDeque::new(),
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+
+    pub fn return_queue(&self) -> &VecDeque<f64> {
+        &self.return_queue
+    }
+
+    pub fn length_queue(&self) -> &VecDeque<usize> {
+        &self.length_queue
+    }
+}
+
+impl<E: Env> Env for RecordEpisodeStatistics<E> {
+    type O = E::O;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        let ri = self.env.reset(seed, options)?;
+        self.episode_return = 0.;
+        self.episode_length = 0;
+        self.episode_start = Instant::now();
+
+        Ok(ri)
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<E::O>> {
+        let mut si = self.env.step(action)?;
+        self.episode_return += si.reward;
+        self.episode_length += 1;
+
+        if si.terminated || si.truncated {
+            let t = self.episode_start.elapsed().as_secs_f64();
+            if !si.info.is_object() {
+                si.info = json!({});
+            }
+            si.info["episode"] = json!(EpisodeStatistics {
+                r: self.episode_return,
+                l: self.episode_length,
+                t: (t * 1e6).round() / 1e6,
+            });
+
+            if self.return_queue.len() == self.deque_size {
+                self.return_queue.pop_front();
+                self.length_queue.pop_front();
+            }
+            self.return_queue.push_back(self.episode_return);
+            self.length_queue.push_back(self.episode_length);
+
+            // NOTE: Also cleared here, inner environments may reset themselves, e.g. when created with auto_reset.
+            self.episode_return = 0.;
+            self.episode_length = 0;
+            self.episode_start = Instant::now();
+        }
+
+        Ok(si)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Clip actions to the bounds of the action space, which then becomes unbounded.
+/// Refer: https://gymnasium.farama.org/api/wrappers/action_wrappers/#gymnasium.wrappers.ClipAction
+#[derive(Debug)]
+pub struct ClipAction<E: Env<A = BoxSpace<Continous>>> {
+    env: E,
+    act_space: BoxSpace<Continous>,
+}
+
+impl<E: Env<A = BoxSpace<Continous>>> ClipAction<E> {
+    pub fn new(env: E) -> Self {
+        let inner = env.action_space();
+        let act_space = BoxSpace {
+            shape: inner.shape.clone(),
+            high: vec![Continous::INFINITY; inner.high.len()],
+            low: vec![Continous::NEG_INFINITY; inner.low.len()],
+        };
+
+        Self { env, act_space }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E: Env<A = BoxSpace<Continous>>> Env for ClipAction<E> {
+    type O = E::O;
+    type A = BoxSpace<Continous>;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &BoxSpace<Continous> {
+        &self.act_space
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        self.env.reset(seed, options)
+    }
+
+    fn step(&mut self, action: &Vec<Continous>) -> GymResult<StepInfo<E::O>> {
+        let inner = self.env.action_space();
+        let action = itertools::izip!(action, &inner.low, &inner.high)
+            .map(|(&a, &l, &h)| a.clamp(l, h))
+            .collect();
+
+        self.env.step(&action)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Affinely rescale actions from [min_action, max_action] to the bounds of the action space.
+/// Refer: https://gymnasium.farama.org/api/wrappers/action_wrappers/#gymnasium.wrappers.RescaleAction
+#[derive(Debug)]
+pub struct RescaleAction<E: Env<A = BoxSpace<Continous>>> {
+    env: E,
+    act_space: BoxSpace<Continous>,
+}
+
+impl<E: Env<A = BoxSpace<Continous>>> RescaleAction<E> {
+    /// NOTE: The inner action space must be bounded, or there is no range to rescale to.
+    pub fn new(env: E, min_action: Continous, max_action: Continous) -> GymResult<Self> {
+        if !(min_action.is_finite() && max_action.is_finite()) || min_action >= max_action {
+            return Err(GymError::InvalidArgument(format!(
+                "RescaleAction range [{min_action}, {max_action}] is empty"
+            )));
+        }
+        let inner = env.action_space();
+        if !inner.is_bounded() {
+            return Err(GymError::InvalidArgument(
+                "RescaleAction of an unbounded action space".to_string(),
+            ));
+        }
+        let act_space = BoxSpace {
+            shape: inner.shape.clone(),
+            high: vec![max_action; inner.high.len()],
+            low: vec![min_action; inner.low.len()],
+        };
+
+        Ok(Self { env, act_space })
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E: Env<A = BoxSpace<Continous>>> Env for RescaleAction<E> {
+    type O = E::O;
+    type A = BoxSpace<Continous>;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &BoxSpace<Continous> {
+        &self.act_space
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        self.env.reset(seed, options)
+    }
+
+    fn step(&mut self, action: &Vec<Continous>) -> GymResult<StepInfo<E::O>> {
+        let inner = self.env.action_space();
+        let action = itertools::izip!(
+            action,
+            &self.act_space.low,
+            &self.act_space.high,
+            &inner.low,
+            &inner.high
+        )
+        .map(|(&a, &min, &max, &l, &h)| (l + (h - l) * ((a - min) / (max - min))).clamp(l, h))
+        .collect();
+
+        self.env.step(&action)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Apply f to observations, of observation_space. Refer: with_space, to change the space.
+/// Refer: https://gymnasium.farama.org/api/wrappers/observation_wrappers/#gymnasium.wrappers.TransformObservation
+pub struct TransformObservation<E, O2, F>
+where
+    E: Env,
+    O2: Space,
+    F: FnMut(<E::O as Space>::Item) -> O2::Item,
+{
+    env: E,
+    obs_space: O2,
+    f: F,
+}
+
+impl<E, F> TransformObservation<E, E::O, F>
+where
+    E: Env,
+    E::O: Clone,
+    F: FnMut(<E::O as Space>::Item) -> <E::O as Space>::Item,
+{
+    /// Keeping the observation space of env.
+    pub fn new(env: E, f: F) -> Self {
+        let obs_space = env.observation_space().clone();
+
+        Self { env, obs_space, f }
+    }
+}
+
+impl<E, O2, F> TransformObservation<E, O2, F>
+where
+    E: Env,
+    O2: Space,
+    F: FnMut(<E::O as Space>::Item) -> O2::Item,
+{
+    pub fn with_space(env: E, observation_space: O2, f: F) -> Self {
+        Self {
+            env,
+            obs_space: observation_space,
+            f,
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E, O2, F> Env for TransformObservation<E, O2, F>
+where
+    E: Env,
+    O2: Space,
+    F: FnMut(<E::O as Space>::Item) -> O2::Item,
+{
+    type O = O2;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &O2 {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        Ok(map_reset(self.env.reset(seed, options)?, &mut self.f))
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<O2>> {
+        Ok(map_observation(self.env.step(action)?, &mut self.f))
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Apply f to rewards.
+/// Refer: https://gymnasium.farama.org/api/wrappers/reward_wrappers/#gymnasium.wrappers.TransformReward
+pub struct TransformReward<E: Env, F: FnMut(f64) -> f64> {
+    env: E,
+    f: F,
+}
+
+impl<E: Env, F: FnMut(f64) -> f64> TransformReward<E, F> {
+    pub fn new(env: E, f: F) -> Self {
+        Self { env, f }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+}
+
+impl<E: Env, F: FnMut(f64) -> f64> Env for TransformReward<E, F> {
+    type O = E::O;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.env.observation_space()
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        self.env.reset(seed, options)
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<E::O>> {
+        let mut si = self.env.step(action)?;
+        si.reward = (self.f)(si.reward);
+
+        Ok(si)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Normalize observations to roughly zero mean and unit variance, by running estimates updated on each
+/// observation. The observation space then becomes unbounded.
+/// Refer: https://gymnasium.farama.org/api/wrappers/observation_wrappers/#gymnasium.wrappers.NormalizeObservation
+#[derive(Debug)]
+pub struct NormalizeObservation<E: Env<O = BoxSpace<Continous>>> {
+    env: E,
+    epsilon: f64,
+    mean: Vec<f64>,
+    var: Vec<f64>,
+    count: f64,
+    obs_space: BoxSpace<Continous>,
+}
+
+impl<E: Env<O = BoxSpace<Continous>>> NormalizeObservation<E> {
+    /// Same defaults as gymnasium, i.e. epsilon = 1e-8.
+    pub fn new(env: E, epsilon: Option<f64>) -> Self {
+        let inner = env.observation_space();
+        let n = inner.low.len();
+        let obs_space = BoxSpace {
+            shape: inner.shape.clone(),
+            high: vec![Continous::INFINITY; n],
+            low: vec![Continous::NEG_INFINITY; n],
+        };
+
+        Self {
+            env,
+            epsilon: epsilon.unwrap_or(1e-8),
+            mean: vec![0.; n],
+            var: vec![1.; n],
+            count: 1e-4,
+            obs_space,
+        }
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+
+    /// Running estimates of the mean and variance of observations.
+    pub fn mean_var(&self) -> (&[f64], &[f64]) {
+        (&self.mean, &self.var)
+    }
+
+    /// Same as gymnasium's RunningMeanStd.update_from_moments, for a batch of one.
+    fn update(&mut self, obs: &[Continous]) {
+        let count = self.count + 1.;
+        for (x, mean, var) in itertools::izip!(obs, &mut self.mean, &mut self.var) {
+            let delta = x - *mean;
+            *mean += delta / count;
+            *var = (*var * self.count + delta * delta * self.count / count) / count;
+        }
+        self.count = count;
+    }
+
+    fn normalize(&self, obs: Vec<Continous>) -> Vec<Continous> {
+        itertools::izip!(obs, &self.mean, &self.var)
+            .map(|(x, mean, var)| (x - mean) / (var + self.epsilon).sqrt())
+            .collect()
+    }
+}
+
+impl<E: Env<O = BoxSpace<Continous>>> Env for NormalizeObservation<E> {
+    type O = BoxSpace<Continous>;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &BoxSpace<Continous> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        let ri = self.env.reset(seed, options)?;
+        self.update(&ri.observation);
+
+        Ok(map_reset(ri, |obs| self.normalize(obs)))
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<Self::O>> {
+        let si = self.env.step(action)?;
+        self.update(&si.observation);
+
+        Ok(map_observation(si, |obs| self.normalize(obs)))
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
+
+/// Stack the last num_stack observations, oldest first, i.e. of shape [num_stack] + shape.
+/// On reset all frames are the initial observation.
+/// NOTE: final_observation is dropped, as it is not part of the stack.
+/// Refer: https://gymnasium.farama.org/api/wrappers/observation_wrappers/#gymnasium.wrappers.FrameStack
+#[derive(Debug)]
+pub struct FrameStack<T: BoxSpaceElement, E: Env<O = BoxSpace<T>>> {
+    env: E,
+    num_stack: usize,
+    frames: VecDeque<Vec<T>>,
+    obs_space: BoxSpace<T>,
+}
+
+impl<T: BoxSpaceElement, E: Env<O = BoxSpace<T>>> FrameStack<T, E> {
+    pub fn new(env: E, num_stack: usize) -> GymResult<Self> {
+        if num_stack == 0 {
+            return Err(GymError::InvalidArgument(
+                "FrameStack needs at least one frame".to_string(),
+            ));
+        }
+        let inner = env.observation_space();
+        let obs_space = BoxSpace {
+            shape: [&[num_stack][..], &inner.shape].concat(),
+            high: inner.high.repeat(num_stack),
+            low: inner.low.repeat(num_stack),
+        };
+
+        Ok(Self {
+            env,
+            num_stack,
+            frames: VecDeque::with_capacity(num_stack),
+            obs_space,
+        })
+    }
+
+    pub fn inner(&self) -> &E {
+        &self.env
+    }
+
+    pub fn inner_mut(&mut self) -> &mut E {
+        &mut self.env
+    }
+
+    fn observation(&self) -> Vec<T> {
+        self.frames.iter().flatten().copied().collect()
+    }
+}
+
+impl<T: BoxSpaceElement, E: Env<O = BoxSpace<T>>> Env for FrameStack<T, E> {
+    type O = BoxSpace<T>;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.env.name()
+    }
+
+    fn observation_space(&self) -> &BoxSpace<T> {
+        &self.obs_space
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.env.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<Self::O>> {
+        let ri = self.env.reset(seed, options)?;
+        self.frames = std::iter::repeat_n(ri.observation, self.num_stack).collect();
+
+        Ok(ResetInfo {
+            observation: self.observation(),
+            info: ri.info,
+        })
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<BoxSpace<T>>> {
+        if self.frames.is_empty() {
+            return Err(GymError::InvalidArgument(
+                "FrameStack stepped before reset".to_string(),
+            ));
+        }
+        let si = self.env.step(action)?;
+        self.frames.pop_front();
+        self.frames.push_back(si.observation);
+
+        Ok(StepInfo {
+            observation: self.observation(),
+            reward: si.reward,
+            truncated: si.truncated,
+            terminated: si.terminated,
+            info: si.info,
+            final_observation: None,
+            final_info: si.final_info,
+        })
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.env.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.env.close()
+    }
+}
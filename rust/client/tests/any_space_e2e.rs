@@ -0,0 +1,48 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::*;
+use serde_json::to_value;
+
+#[test]
+fn any_space_e2e() {
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        "http://127.0.0.1:40004",
+        "FrozenLake-v1",
+        None,
+        None,
+        None,
+        &[("desc", to_value(["GGGH", "GSGH", "GGGF", "FFFG"]).unwrap())],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    let any =
+        Environment::<AnySpace, AnySpace>::reference(env.client_base_url(), env.instance_id())
+            .unwrap();
+    assert_eq!(any.observation_space().name(), "Discrete");
+    assert_eq!(any.observation_space().as_discrete().unwrap().n, 16);
+    assert_eq!(any.action_space().as_discrete().unwrap().n, 4);
+
+    let s = any.reset(Some(2718), None).unwrap().observation;
+    assert_eq!(s, AnyItem::Discrete(5));
+
+    let si = any.step(&AnyItem::Discrete(1)).unwrap();
+    assert!(si.observation.as_discrete().is_some());
+
+    let env = Environment::<AnySpace, AnySpace>::new(
+        "http://127.0.0.1:40004",
+        "MountainCarContinuous-v0",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(env.observation_space().as_box().unwrap().shape, [2]);
+    let s = env.reset(Some(2718), None).unwrap().observation;
+    assert_eq!(s.as_box_continous().unwrap().len(), 2);
+    let action = env.action_space_sample().unwrap();
+    assert_eq!(action.as_box_continous().unwrap().len(), 1);
+}
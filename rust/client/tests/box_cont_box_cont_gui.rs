@@ -20,8 +20,10 @@ fn box_cont_box_cont_gui() {
             ("render_mode", to_value("rgb_array").unwrap()),
             ("goal_velocity", to_value(false).unwrap()),
         ],
-    );
-    assert_eq!(env.name(), "MountainCarContinuous-v0");
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(env.name().unwrap(), "MountainCarContinuous-v0");
 
     let osvs = env.observation_space();
     assert_eq!(osvs.shape, [2]);
@@ -32,22 +34,22 @@ fn box_cont_box_cont_gui() {
     assert_float_eq!(asvs.high, vec![1.0], rmax_all <= 1e-7);
     assert_float_eq!(asvs.low, vec![-1.0], rmax_all <= 1e-7);
 
-    let s = env.reset(Some(2718));
+    let s = env.reset(Some(2718), None).unwrap().observation;
     assert_float_eq!(s, vec![-0.546957671, 0.0], rmax_all <= 1e-7);
 
-    let rf = env.render();
+    let rf = env.render().unwrap();
     let data = rf.as_rgb().unwrap();
     assert_eq!(
         (
             *data.0,
             *data.1,
-            deserialize_binary_stream_to_bytes(data.2).len()
+            deserialize_binary_stream_to_bytes(data.2).unwrap().len()
         ),
         (400, 600, 960000)
     );
 
-    let action = env.action_space_sample();
-    let si = env.step(&action);
+    let action = env.action_space_sample().unwrap();
+    let si = env.step(&action).unwrap();
     let obs = si.observation;
     assert_eq!(obs.len(), osvs.shape[0] as usize);
     assert!(osvs.high[0] >= obs[0] && obs[0] >= osvs.low[0]);
@@ -11,15 +11,17 @@ use serde_json::to_value;
 /// Refer: https://www.gymlibrary.dev/environments/classic_control/mountain_car_continuous/#mountain-car-continuous
 #[test]
 fn box_discrete_box_cont_gui() {
-    let env = Environment::<BoxSpace<Discrete>, BoxSpace<Continous>>::new(
+    let env = Environment::<BoxSpace<u8>, BoxSpace<Continous>>::new(
         "http://127.0.0.1:40004",
         "CarRacing-v2",
         None,
         None,
         None,
         &[("render_mode", to_value("rgb_array").unwrap())],
-    );
-    assert_eq!(env.name(), "CarRacing-v2");
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(env.name().unwrap(), "CarRacing-v2");
 
     let osvs = env.observation_space();
     assert_eq!(osvs.shape, [96, 96, 3]);
@@ -30,17 +32,18 @@ fn box_discrete_box_cont_gui() {
     assert_float_eq!(asvs.low, vec![-1., 0., 0.], rmax_all <= 1e-7);
     assert_float_eq!(asvs.high, vec![1., 1., 1.], rmax_all <= 1e-7);
 
-    let s = env.reset(None);
+    let s = env.reset(None, None).unwrap().observation;
     assert_eq!(osvs.shape.iter().product::<usize>(), s.len());
     assert_ne!(0, s.iter().filter(|&&x| x != 0).count());
+    assert_eq!(osvs.shaped(s).unwrap().shape(), [96, 96, 3]);
 
-    let rf = env.render();
+    let rf = env.render().unwrap();
     let data = rf.as_rgb().unwrap();
     assert_eq!(
         (
             *data.0,
             *data.1,
-            deserialize_binary_stream_to_bytes(data.2).len()
+            deserialize_binary_stream_to_bytes(data.2).unwrap().len()
         ),
         (400, 600, 960000)
     );
@@ -0,0 +1,75 @@
+extern crate gymnasium;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::*;
+
+const API_URL: &str = "http://127.0.0.1:40004";
+
+fn frozen_lake() -> Environment<DiscreteSpace, DiscreteSpace> {
+    Environment::new(API_URL, "FrozenLake-v1", None, None, None, &[]).unwrap()
+}
+
+fn is_served(instance_id: &str) -> bool {
+    Environment::<DiscreteSpace, DiscreteSpace>::envs(API_URL)
+        .unwrap()
+        .contains_key(instance_id)
+}
+
+#[test]
+fn close() {
+    let env = frozen_lake();
+    let instance_id = env.instance_id().to_string();
+    assert!(is_served(&instance_id));
+
+    env.close().unwrap();
+    assert!(!is_served(&instance_id));
+    assert!(env.close().is_ok());
+    assert!(env.reset(None, None).is_err());
+}
+
+#[test]
+fn close_on_drop() {
+    let env = frozen_lake().close_on_drop();
+    let instance_id = env.instance_id().to_string();
+    assert!(is_served(&instance_id));
+
+    drop(env);
+    assert!(!is_served(&instance_id));
+}
+
+#[test]
+fn drop_keeps_instances() {
+    let env = frozen_lake();
+    let instance_id = env.instance_id().to_string();
+    drop(env);
+    assert!(is_served(&instance_id));
+
+    // References never own the instance, even when opting in.
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::reference(API_URL, &instance_id)
+        .unwrap()
+        .close_on_drop();
+    drop(env);
+    assert!(is_served(&instance_id));
+
+    Environment::<DiscreteSpace, DiscreteSpace>::reference(API_URL, &instance_id)
+        .unwrap()
+        .close()
+        .unwrap();
+    assert!(!is_served(&instance_id));
+}
+
+#[test]
+fn space_mismatch_closes_the_created_instance() {
+    let res = Environment::<BoxSpace<Continous>, DiscreteSpace>::new(
+        API_URL,
+        "Taxi-v3",
+        None,
+        None,
+        None,
+        &[],
+    );
+    assert!(matches!(res, Err(GymError::SpaceMismatch { .. })));
+
+    let envs = Environment::<DiscreteSpace, DiscreteSpace>::envs(API_URL).unwrap();
+    assert!(envs.values().all(|id| id != "Taxi-v3"));
+}
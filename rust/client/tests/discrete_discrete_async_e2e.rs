@@ -0,0 +1,54 @@
+extern crate float_eq;
+extern crate gymnasium;
+extern crate serde_json;
+extern crate tokio;
+
+use float_eq::*;
+use gymnasium::*;
+use serde_json::to_value;
+
+#[test]
+fn discrete_discrete_async_e2e() {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(async {
+        let env = AsyncEnvironment::<DiscreteSpace, DiscreteSpace>::new(
+            "http://127.0.0.1:40004",
+            "FrozenLake-v1",
+            Some(1),
+            Some(false),
+            Some(true),
+            &[
+                ("render_mode", to_value("ansi").unwrap()),
+                ("is_slippery", to_value(false).unwrap()),
+                ("desc", to_value(["GGGH", "GSGH", "GGGF", "FFFG"]).unwrap()),
+            ],
+        )
+        .await
+        .unwrap();
+        assert_eq!(env.name().await.unwrap(), "FrozenLake-v1");
+        assert_eq!(env.observation_space().n, 16);
+        assert_eq!(env.action_space().n, 4);
+        let envs = AsyncEnvironment::<DiscreteSpace, DiscreteSpace>::envs("http://127.0.0.1:40004")
+            .await
+            .unwrap();
+        assert!(envs.contains_key(env.instance_id()));
+
+        let ri = env.reset(Some(2718), None).await.unwrap();
+        assert_eq!(ri.observation, 5);
+        assert!(ri.info.is_object());
+
+        let si = env.step(&1).await.unwrap();
+        assert_eq!(si.observation, 9);
+        assert!(si.terminated);
+        assert!(si.truncated);
+        assert_float_eq!(si.reward, 1., rmax <= 1e-16);
+
+        env.close().await.unwrap();
+        let envs = AsyncEnvironment::<DiscreteSpace, DiscreteSpace>::envs("http://127.0.0.1:40004")
+            .await
+            .unwrap();
+        assert!(!envs.contains_key(env.instance_id()));
+        assert!(env.close().await.is_ok());
+        assert!(env.reset(None, None).await.is_err());
+    });
+}
@@ -20,28 +20,31 @@ fn discrete_discrete_tui_e2e() {
             ("is_slippery", to_value(false).unwrap()),
             ("desc", to_value(["GGGH", "GSGH", "GGGF", "FFFG"]).unwrap()),
         ],
-    );
-    assert_eq!(env.name(), "FrozenLake-v1");
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(env.name().unwrap(), "FrozenLake-v1");
     assert_eq!(env.observation_space().n, 16);
     assert_eq!(env.action_space().n, 4);
-    assert_eq!(transitions(&env).len(), 64);
+    assert_eq!(transitions(&env).unwrap().len(), 64);
 
-    let s = env.reset(Some(2718));
-    assert_eq!(s, 5);
+    let ri = env.reset(Some(2718), None).unwrap();
+    assert_eq!(ri.observation, 5);
+    assert!(ri.info.is_object());
 
-    let rf = env.render();
+    let rf = env.render().unwrap();
     assert_eq!(
         rf.as_str().unwrap(),
         "\nGGGH\nG\u{1b}[41mS\u{1b}[0mGH\nGGGF\nFFFG\n"
     );
 
-    let si = env.step(&1);
+    let si = env.step(&1).unwrap();
     assert_eq!(si.observation, 9);
     assert_eq!(format!("terminated: {}", si.terminated), "terminated: true");
     assert_eq!(format!("truncated: {}", si.truncated), "truncated: true");
     assert_float_eq!(si.reward, 1., rmax <= 1e-16);
 
-    let rf = env.render();
+    let rf = env.render().unwrap();
     assert_eq!(
         rf.as_str().unwrap(),
         "  (Down)\nGGGH\nGSGH\nG\u{1b}[41mG\u{1b}[0mGF\nFFFG\n"
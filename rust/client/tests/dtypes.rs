@@ -0,0 +1,92 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, error::*, utils::*, wire_format::*};
+use gymnasium::*;
+use serde_json::json;
+
+fn stream(xs: &[impl Copy + Into<f64>], f: impl Fn(f64) -> Vec<u8>) -> String {
+    let bytes = xs.iter().flat_map(|&x| f(x.into())).collect::<Vec<_>>();
+    serialize_bytes_to_binary_stream(&bytes)
+}
+
+#[test]
+fn dtypes_converted_losslessly() {
+    let pixels = stream(&[0u8, 128, 255], |x| vec![x as u8]);
+    assert_eq!(
+        deserialize_binary_stream::<u8>("uint8", &pixels).unwrap(),
+        [0, 128, 255]
+    );
+    assert_eq!(
+        deserialize_binary_stream::<Discrete>("uint8", &pixels).unwrap(),
+        [0, 128, 255]
+    );
+    assert_eq!(
+        deserialize_binary_stream::<Continous>("uint8", &pixels).unwrap(),
+        [0., 128., 255.]
+    );
+
+    let xs = stream(&[0.1f32, -2.5], |x| (x as f32).to_le_bytes().to_vec());
+    assert_eq!(
+        deserialize_binary_stream::<f32>("float32", &xs).unwrap(),
+        [0.1, -2.5]
+    );
+    assert_eq!(
+        deserialize_binary_stream::<Continous>("float32", &xs).unwrap(),
+        [0.1f32 as f64, -2.5]
+    );
+
+    let xs = stream(&[-1i8, 1], |x| (x as i8).to_le_bytes().to_vec());
+    assert_eq!(
+        deserialize_binary_stream::<i8>("int8", &xs).unwrap(),
+        [-1, 1]
+    );
+    assert_eq!(
+        deserialize_binary_stream::<i32>("int8", &xs).unwrap(),
+        [-1, 1]
+    );
+}
+
+#[test]
+fn dtypes_lossy_conversion_reported() {
+    let xs = stream(&[255u8, 0], |x| vec![x as u8]);
+    assert!(matches!(
+        deserialize_binary_stream::<i8>("uint8", &xs),
+        Err(GymError::LossyConversion { value, .. }) if value == "255"
+    ));
+
+    let xs = stream(&[0.1], |x| x.to_le_bytes().to_vec());
+    assert!(matches!(
+        deserialize_binary_stream::<f32>("float64", &xs),
+        Err(GymError::LossyConversion { .. })
+    ));
+    assert!(matches!(
+        deserialize_binary_stream::<Discrete>("float64", &xs),
+        Err(GymError::LossyConversion { .. })
+    ));
+
+    assert!(matches!(
+        deserialize_binary_stream::<Continous>("complex64", &xs),
+        Err(GymError::DtypeMismatch { .. })
+    ));
+}
+
+#[test]
+fn box_of_pixels() {
+    let space = BoxSpace::<u8>::new(&json!({ "info": {
+        "name": "Box",
+        "shape": [2, 2, 1],
+        "low": [0, 0, 0, 0],
+        "high": [255, 255, 255, 255],
+    }}))
+    .unwrap();
+
+    let obs = json!({
+        "type": "uint8",
+        "shape": [2, 2, 1],
+        "data": stream(&[1u8, 2, 3, 4], |x| vec![x as u8]),
+    });
+    let s = space.observation(&WireValue::from(&obs)).unwrap();
+    assert!(space.contains(&s));
+    assert_eq!(space.shaped(s).unwrap()[[1, 0, 0]], 3);
+}
@@ -0,0 +1,49 @@
+extern crate gymnasium;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::envs::*;
+use gymnasium::*;
+use std::rc::Rc;
+
+struct AlwaysRight;
+
+impl Policy<BoxSpace<Continous>, DiscreteSpace> for AlwaysRight {
+    fn policy(&self, _s: &Vec<Continous>) -> GymResult<Discrete> {
+        Ok(1)
+    }
+}
+
+#[test]
+fn policy_episodes() {
+    let env = LocalEnvironment::new(CartPole::new());
+    let ep_gen = PolicyEpisodeGenerator::new(env, Rc::new(AlwaysRight), Some(5));
+
+    let (_, steps) = ep_gen.episode(Some(42)).unwrap();
+    assert_eq!(steps.len(), 5);
+    assert!(steps[4].truncated);
+    assert!(!steps[4].terminated);
+
+    let eps: Vec<Vec<EpisodeEvent<Continous>>> = ep_gen.generate(3, Some(42)).unwrap();
+    assert_eq!(eps.len(), 3);
+    assert!(eps.iter().all(|ep| ep.len() == 6));
+    assert_eq!(eps[0][0].r, 0.);
+    assert!(eps[0][1..].iter().all(|e| e.r == 1.));
+
+    // Per episode seeds, i.e. reproducible.
+    let mut env = ep_gen.into_inner();
+    let s0 = env.reset(Some(43), None).unwrap().observation;
+    assert_eq!(eps[1][0].s, s0);
+    assert_ne!(eps[0][0].s, eps[1][0].s);
+
+    let ep_gen = PolicyEpisodeGenerator::new(env, Rc::new(AlwaysRight), None);
+    let again: Vec<Vec<EpisodeEvent<Continous>>> = ep_gen.generate(3, Some(42)).unwrap();
+    assert_eq!(again[2][0].s, eps[2][0].s);
+    // Terminates on its own, pushing right.
+    assert!(again.iter().all(|ep| ep.len() > 6));
+
+    // Per episode seeds wrap around.
+    let wrapped: Vec<Vec<EpisodeEvent<Continous>>> = ep_gen.generate(2, Some(usize::MAX)).unwrap();
+    let mut env = ep_gen.into_inner();
+    let s0 = env.reset(Some(0), None).unwrap().observation;
+    assert_eq!(wrapped[1][0].s, s0);
+}
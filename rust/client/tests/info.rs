@@ -0,0 +1,78 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, error::*, utils::*};
+use gymnasium::envs::*;
+use gymnasium::info::*;
+use gymnasium::wrappers::*;
+use gymnasium::*;
+use serde_json::json;
+
+struct Down;
+
+impl Policy<DiscreteSpace, DiscreteSpace> for Down {
+    fn policy(&self, _s: &Discrete) -> GymResult<Discrete> {
+        Ok(0)
+    }
+}
+
+#[test]
+fn native_info() {
+    let mut env = LocalEnvironment::new(Taxi::new(None));
+    let info = env
+        .reset(Some(42), None)
+        .unwrap()
+        .info_as::<TaxiInfo>()
+        .unwrap();
+    assert_eq!(info.prob, 1.);
+    assert_eq!(info.action_mask, [1, 1, 0, 1, 0, 0]);
+    let info = env.step(&0).unwrap().info_as::<TaxiInfo>().unwrap();
+    assert_eq!(info.prob, 1.);
+    assert_eq!(info.action_mask.len(), 6);
+    assert!(info.is_valid(1));
+    assert!(!info.is_valid(6));
+
+    let env = LocalEnvironment::new(CliffWalking::new(None));
+    let mut env = RecordEpisodeStatistics::new(TimeLimit::new(env, 3), None);
+    let ri = env.reset(Some(0), None).unwrap();
+    assert_eq!(ri.info_as::<TransitionInfo>().unwrap().prob, 1.);
+    let (_, steps) = rollout(&mut env, &Down, Some(0)).unwrap();
+    assert_eq!(steps[0].info_as::<TransitionInfo>().unwrap().prob, 1.);
+    assert_eq!(steps[0].info_as::<EpisodeInfo>().unwrap().episode, None);
+
+    let episode = steps[2].info_as::<EpisodeInfo>().unwrap().episode.unwrap();
+    assert_eq!(episode.l, 3);
+    assert_eq!(episode.r, -3.);
+
+    assert!(matches!(
+        steps[0].info_as::<TaxiInfo>(),
+        Err(GymError::MalformedJson(_))
+    ));
+}
+
+#[test]
+fn binary_stream_info() {
+    // As sent by the Python server for np.array([1, 0, 1], dtype=np.int8).
+    let info = json!({
+        "prob": 1.0,
+        "action_mask": {
+            "type": "int8",
+            "shape": [3],
+            "data": serialize_bytes_to_binary_stream(&[1, 0, 1]),
+        },
+    });
+    let info: TaxiInfo = serde_json::from_value(info).unwrap();
+    assert_eq!(info.action_mask, [1, 0, 1]);
+
+    // Raw bytes, as decoded from MessagePack.
+    let val = json!({ "type": "int16", "shape": [2], "data": [1, 0, 255, 255] });
+    assert_eq!(array_from_value::<i32>(&val).unwrap(), [1, -1]);
+    assert_eq!(
+        array_from_value::<f64>(&json!([0.5, 2])).unwrap(),
+        [0.5, 2.]
+    );
+    assert!(matches!(
+        array_from_value::<u8>(&json!([-1])),
+        Err(GymError::MalformedJson(_))
+    ));
+}
@@ -0,0 +1,73 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::envs::*;
+use gymnasium::*;
+use serde_json::json;
+
+struct AlwaysRight;
+
+impl Policy<BoxSpace<Continous>, DiscreteSpace> for AlwaysRight {
+    fn policy(&self, _s: &Vec<Continous>) -> GymResult<Discrete> {
+        Ok(1)
+    }
+}
+
+/// Written once against Env, i.e. same for served and in-process environments.
+fn total_reward<E: Env>(env: &mut E, policy: &dyn Policy<E::O, E::A>, seed: u64) -> f64 {
+    let (_, steps) = rollout(env, policy, Some(seed)).unwrap();
+    steps.iter().map(|si| si.reward).sum()
+}
+
+#[test]
+fn local_env_rollout() {
+    let mut env = LocalEnvironment::new(CartPole::new());
+    assert_eq!(env.name().unwrap(), "CartPole");
+    assert_eq!(env.action_space().n, 2);
+
+    let (s0, steps) = rollout(&mut env, &AlwaysRight, Some(42)).unwrap();
+    assert!(env.observation_space().contains(&s0));
+    assert!(steps.last().unwrap().terminated);
+    assert!(steps[..steps.len() - 1].iter().all(|si| !si.terminated));
+
+    assert_eq!(total_reward(&mut env, &AlwaysRight, 42), steps.len() as f64);
+    assert!(env.close().is_ok());
+}
+
+#[test]
+fn local_env_render() {
+    let mut env = LocalEnvironment::with_name(
+        FrozenLake::new(
+            FrozenLake::map("4x4").unwrap(),
+            false,
+            Some(RenderMode::Ansi),
+        )
+        .unwrap(),
+        "FrozenLake-v1",
+    );
+    assert_eq!(env.name().unwrap(), "FrozenLake-v1");
+
+    let ri = env.reset(Some(0), None).unwrap();
+    assert_eq!(ri.observation, 0);
+    assert_eq!(ri.info, json!({ "prob": 1 }));
+    assert!(env
+        .render()
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .starts_with("\n\x1b[41mS"));
+
+    let env = LocalEnvironment::new(CartPole::new());
+    assert!(matches!(env.render(), Err(GymError::Unsupported(_))));
+}
+
+#[test]
+fn local_env_invalid_action() {
+    let mut env = LocalEnvironment::new(
+        FrozenLake::new(FrozenLake::map("4x4").unwrap(), false, None).unwrap(),
+    );
+    env.reset(Some(0), None).unwrap();
+    assert!(matches!(env.step(&4), Err(GymError::InvalidArgument(_))));
+    assert_eq!(env.step(&1).unwrap().observation, 4);
+}
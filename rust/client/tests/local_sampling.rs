@@ -0,0 +1,109 @@
+extern crate gymnasium;
+extern crate rand;
+extern crate serde_json;
+
+use gymnasium::{common::defs::*, common::error::*, *};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde_json::json;
+
+#[test]
+fn discrete_sample_and_contains() {
+    let space = DiscreteSpace::new(&json!({ "info": { "name": "Discrete", "n": 4 } })).unwrap();
+    let rng = &mut StdRng::seed_from_u64(2718);
+
+    assert!((0..100).all(|_| space.contains(&space.sample(rng).unwrap())));
+    assert!(!space.contains(&4));
+    assert!(!space.contains(&-1));
+}
+
+#[test]
+fn box_sample_and_contains() {
+    let space = BoxSpace::<Continous>::new(&json!({ "info": {
+        "name": "Box",
+        "shape": [3],
+        "low": [-1.0, 0.0, f64::MIN],
+        "high": [1.0, f64::MAX, f64::MAX],
+    }}))
+    .unwrap();
+    assert_eq!(space.low[2], f64::NEG_INFINITY);
+    assert_eq!(space.high[1..], [f64::INFINITY, f64::INFINITY]);
+    assert_eq!(space.is_bounded_below(), [true, true, false]);
+    assert_eq!(space.is_bounded_above(), [true, false, false]);
+    assert!(!space.is_bounded());
+    let rng = &mut StdRng::seed_from_u64(2718);
+
+    for _ in 0..100 {
+        let x = space.sample(rng).unwrap();
+        assert!(space.contains(&x));
+        assert!(x[2].is_finite());
+    }
+    assert!(!space.contains(&vec![1.1, 0., 0.]));
+    assert!(!space.contains(&vec![0., 0.]));
+}
+
+#[test]
+fn multi_spaces_sample_and_contains() {
+    let md = MultiDiscreteSpace::new(&json!({ "info": {
+        "name": "MultiDiscrete",
+        "shape": [2],
+        "nvec": [3, 2],
+        "start": [1, -1],
+    }}))
+    .unwrap();
+    let mb = MultiBinarySpace::new(&json!({ "info": { "name": "MultiBinary", "shape": [2, 2] } }))
+        .unwrap();
+    let rng = &mut StdRng::seed_from_u64(2718);
+
+    assert!((0..100).all(|_| md.contains(&md.sample(rng).unwrap())));
+    assert!(!md.contains(&vec![0, 0]));
+    assert!((0..100).all(|_| mb.contains(&mb.sample(rng).unwrap())));
+    assert!(!mb.contains(&vec![0, 1, 2, 0]));
+}
+
+#[test]
+fn tuple_sample_is_reproducible() {
+    let space = TupleSpace::new(&json!({ "info": {
+        "name": "Tuple",
+        "spaces": [
+            { "name": "Discrete", "n": 32 },
+            { "name": "Box", "shape": [1], "low": [0.0], "high": [1.0] },
+        ],
+    }}))
+    .unwrap();
+
+    let s1 = space.sample(&mut StdRng::seed_from_u64(2718)).unwrap();
+    let s2 = space.sample(&mut StdRng::seed_from_u64(2718)).unwrap();
+    assert_eq!(s1, s2);
+    assert!(space.contains(&s1));
+    assert!(!space.contains(&vec![AnyItem::Discrete(32), s1[1].clone()]));
+}
+
+#[test]
+fn box_shaped() {
+    let space = BoxSpace::<Discrete>::new(&json!({ "info": {
+        "name": "Box",
+        "shape": [2, 3],
+        "low": [0, 0, 0, 0, 0, 0],
+        "high": [0, 1, 2, 3, 4, 5],
+    }}))
+    .unwrap();
+
+    let high = space.high_array().unwrap();
+    assert_eq!(high.shape(), [2, 3]);
+    assert_eq!(high[[1, 0]], 3);
+    assert!(space.low_array().unwrap().iter().all(|&x| x == 0));
+
+    let s = space.shaped(vec![0, 1, 0, 1, 0, 1]).unwrap();
+    assert_eq!(s.shape(), [2, 3]);
+    assert_eq!(s[[0, 1]], 1);
+    assert!(space.shaped(vec![0; 5]).is_err());
+}
+
+#[test]
+fn unknown_space_sample_is_unsupported() {
+    let space = AnySpace::new(&json!({ "info": { "name": "Text", "max_length": 4 } })).unwrap();
+
+    let res = space.sample(&mut StdRng::seed_from_u64(2718));
+    assert!(matches!(res, Err(GymError::Unsupported(_))));
+}
@@ -0,0 +1,124 @@
+extern crate float_eq;
+extern crate gymnasium;
+extern crate rand;
+
+use float_eq::*;
+use gymnasium::common::defs::*;
+use gymnasium::envs::*;
+use rand::SeedableRng;
+
+#[test]
+fn np_random_same_as_numpy() {
+    for (seed, x) in [
+        (0, 0.6369616873214543),
+        (42, 0.7739560485559633),
+        (12345, 0.22733602246716966),
+    ] {
+        assert_eq!(NpRandom::seed_from_u64(seed).random(), x);
+    }
+}
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/cart_pole/
+#[test]
+fn cartpole_native() {
+    let mut env = CartPole::new();
+    assert_eq!(env.action_space().n, 2);
+    assert_eq!(env.observation_space().shape, [4]);
+
+    let s = env.reset(Some(42)).observation;
+    assert_float_eq!(
+        s,
+        vec![0.0273956, -0.00611216, 0.03585979, 0.0197368],
+        rmax_all <= 1e-6
+    );
+    assert!(env.observation_space().contains(&s));
+
+    // Pushing in the same direction topples the pole.
+    let mut steps = 0;
+    let si = loop {
+        let si = env.step(&1);
+        steps += 1;
+        if si.terminated {
+            break si;
+        }
+        assert_eq!(si.reward, 1.);
+    };
+    assert!(steps < 50);
+    assert_eq!(si.reward, 1.);
+    assert!(!si.truncated);
+    assert_eq!(env.step(&1).reward, 0.);
+    assert!(env.render().is_none());
+}
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/mountain_car_continuous/
+#[test]
+fn mountain_car_continuous_native() {
+    let mut env = MountainCarContinuous::new(0.);
+    assert_float_eq!(
+        env.observation_space().high,
+        vec![0.6, 0.07],
+        rmax_all <= 1e-7
+    );
+    assert_float_eq!(env.action_space().low, vec![-1.0], rmax_all <= 1e-7);
+
+    let s = env.reset(Some(2718)).observation;
+    assert_float_eq!(s, vec![-0.546957671, 0.0], rmax_all <= 1e-7);
+
+    let si = env.step(&vec![0.5]);
+    assert!(env.observation_space().contains(&si.observation));
+    assert_float_eq!(si.reward, -0.025, abs <= 1e-7);
+    assert!(!si.terminated);
+}
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/mountain_car/
+#[test]
+fn mountain_car_native() {
+    let mut env = MountainCar::new(0.);
+    let s0 = env.reset(Some(2718)).observation;
+    assert_float_eq!(s0, vec![-0.546957671, 0.0], rmax_all <= 1e-7);
+
+    // Same seed, same episode.
+    let run = |env: &mut MountainCar| {
+        env.reset(Some(2718));
+        (0..20)
+            .map(|_| env.step(&2).observation)
+            .collect::<Vec<_>>()
+    };
+    let obs = run(&mut env);
+    assert_eq!(obs, run(&mut env));
+    assert!(obs[19][1] > 0.);
+}
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/pendulum/
+#[test]
+fn pendulum_native() {
+    let mut env = Pendulum::new(10.);
+    assert_eq!(env.observation_space().shape, [3]);
+
+    let s = env.reset(Some(0)).observation;
+    assert_float_eq!(s[0].powi(2) + s[1].powi(2), 1., abs <= 1e-6);
+
+    for _ in 0..200 {
+        let si = env.step(&vec![2.]);
+        assert!(si.reward <= 0.);
+        assert!(!si.terminated);
+        assert!(env.observation_space().contains(&si.observation));
+    }
+}
+
+/// Refer: https://gymnasium.farama.org/environments/classic_control/acrobot/
+#[test]
+fn acrobot_native() {
+    let mut env = Acrobot::new();
+    assert_eq!(env.action_space().n, 3);
+
+    let s = env.reset(Some(0)).observation;
+    assert_eq!(s.len(), 6);
+    assert!(env.observation_space().contains(&s));
+
+    for _ in 0..10 {
+        let si = env.step(&0);
+        assert!(env.observation_space().contains(&si.observation));
+        assert_eq!(si.reward, -1.);
+    }
+}
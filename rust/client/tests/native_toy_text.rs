@@ -0,0 +1,153 @@
+extern crate gymnasium;
+extern crate rand;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::envs::*;
+use gymnasium::*;
+use rand::SeedableRng;
+use serde_json::json;
+
+#[test]
+fn np_random_integers_same_as_numpy() {
+    let mut rng = NpRandom::seed_from_u64(42);
+    let xs = (0..5).map(|_| rng.integers(0, 10)).collect::<Vec<_>>();
+
+    assert_eq!(xs, [0, 7, 6, 4, 4]);
+}
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/taxi/
+#[test]
+fn taxi_native() {
+    let mut env = Taxi::new(Some(RenderMode::Ansi));
+    assert_eq!(env.observation_space().n, 500);
+    assert_eq!(env.action_space().n, 6);
+    assert_eq!(env.transitions().unwrap().len(), 500 * 6);
+
+    let ri = env.reset(Some(42));
+    let s = ri.observation;
+    assert_eq!(s, 386);
+    assert_eq!(
+        ri.info,
+        json!({ "prob": 1., "action_mask": [1, 1, 0, 1, 0, 0] })
+    );
+    assert_eq!(Taxi::decode(s), (3, 4, 1, 2));
+    assert_eq!(Taxi::encode(3, 4, 1, 2), s);
+    assert_eq!(
+        env.render().unwrap().as_str().unwrap(),
+        "+---------+\n\
+         |R: | : :\x1b[34;1mG\x1b[0m|\n\
+         | : | : : |\n\
+         | : : : : |\n\
+         | | : | :\x1b[43m \x1b[0m|\n\
+         |\x1b[35mY\x1b[0m| : |B: |\n\
+         +---------+\n\n"
+    );
+
+    // Picking up where there is no passenger.
+    let si = env.step(&4);
+    assert_eq!(si.observation, s);
+    assert_eq!(si.reward, -10.);
+    assert_eq!(si.info["action_mask"], json!([1, 1, 0, 1, 0, 0]));
+    assert!(env
+        .render()
+        .unwrap()
+        .as_str()
+        .unwrap()
+        .ends_with("  (Pickup)\n"));
+}
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/cliff_walking/
+#[test]
+fn cliff_walking_native() {
+    let mut env = CliffWalking::new(Some(RenderMode::Ansi));
+    assert_eq!(env.observation_space().n, 48);
+
+    let ri = env.reset(Some(2718));
+    assert_eq!(ri.observation, 36);
+    assert_eq!(ri.info, json!({ "prob": 1 }));
+    let row = |cells: [&str; 12]| format!("{}  {}\n", cells[..11].join("  "), cells[11]);
+    assert_eq!(
+        env.render().unwrap().as_str().unwrap(),
+        format!(
+            "{}{}{}{}\n",
+            row(["o"; 12]),
+            row(["o"; 12]),
+            row(["o"; 12]),
+            row(["x", "C", "C", "C", "C", "C", "C", "C", "C", "C", "C", "T"]),
+        )
+    );
+
+    // Falling off the cliff.
+    let si = env.step(&1);
+    assert_eq!(
+        (si.observation, si.reward, si.terminated),
+        (36, -100., false)
+    );
+
+    let si = env.step(&0);
+    assert_eq!((si.observation, si.reward, si.terminated), (24, -1., false));
+}
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/blackjack/
+#[test]
+fn blackjack_native() {
+    let mut env = Blackjack::new(false, true);
+    let ns = env
+        .observation_space()
+        .spaces
+        .iter()
+        .map(|s| s.as_discrete().unwrap().n)
+        .collect::<Vec<_>>();
+    assert_eq!(ns, [32, 11, 2]);
+
+    let obs = |xs: [Discrete; 3]| xs.map(AnyItem::Discrete).to_vec();
+
+    // Dealer busts.
+    let ri = env.reset(Some(42));
+    assert_eq!(ri.observation, obs([15, 2, 0]));
+    assert_eq!(ri.info, json!({}));
+    let si = env.step(&0);
+    assert!(si.terminated);
+    assert_eq!(si.reward, 1.);
+
+    // Natural, with the sab rules.
+    assert_eq!(env.reset(Some(2718)).observation, obs([21, 10, 1]));
+    let si = env.step(&0);
+    assert!(si.terminated);
+    assert_eq!(si.reward, 1.);
+    assert!(env.render().is_none());
+}
+
+#[test]
+fn frozen_lake_native_transitions() {
+    let env = FrozenLake::new(FrozenLake::map("4x4").unwrap(), true, None).unwrap();
+    let ts = env.transitions().unwrap();
+
+    assert_eq!(ts.len(), 16 * 4);
+    assert!(ts
+        .values()
+        .all(|ts| (ts.iter().map(|t| t.probability).sum::<Continous>() - 1.).abs() < 1e-12));
+    assert_eq!(ts[&(14, 2)].iter().filter(|t| t.reward == 1.).count(), 1);
+
+    let model = TransitionModel::from_native(&env).unwrap();
+    let p = model.p();
+    assert_eq!(p.dim(), (16, 4, 16));
+    assert!((p.sum() - 16. * 4.).abs() < 1e-9);
+    assert!((p[[0, 2, 4]] - 1. / 3.).abs() < 1e-12);
+    assert!((model.r()[[14, 2]] - 1. / 3.).abs() < 1e-12);
+
+    let mut missing = ts.clone();
+    missing.remove(&(0, 0));
+    assert!(matches!(
+        TransitionModel::new(16, 4, missing),
+        Err(GymError::MalformedJson(_))
+    ));
+    let mut out_of_range = ts.clone();
+    out_of_range.get_mut(&(0, 0)).unwrap()[0].next_state = 16;
+    assert!(matches!(
+        TransitionModel::new(16, 4, out_of_range),
+        Err(GymError::MalformedJson(_))
+    ));
+    assert!(TransitionModel::new(16, 4, ts.clone()).is_ok());
+}
@@ -0,0 +1,51 @@
+extern crate gymnasium;
+extern crate png;
+
+use gymnasium::common::{error::*, utils::*};
+use gymnasium::*;
+
+/// 2 rows x 3 cols, as sent by the server for an rgb_array.
+fn frame() -> (Vec<u8>, RenderFrame) {
+    let pixels = (0..2 * 3 * 4).map(|x| x as u8).collect::<Vec<_>>();
+    let rf = RenderFrame::Rgb(2, 3, serialize_bytes_to_binary_stream(&pixels));
+
+    (pixels, rf)
+}
+
+#[test]
+fn render_frame_decoded() {
+    let (pixels, rf) = frame();
+
+    let frame = rf.to_rgba().unwrap();
+    assert_eq!((frame.width(), frame.height()), (3, 2));
+    assert_eq!(frame.pixels(), pixels);
+
+    let a = rf.to_array().unwrap();
+    assert_eq!(a.dim(), (2, 3, 4));
+    // Last pixel of the first row.
+    assert_eq!(a[[0, 2, 0]], 8);
+    assert_eq!(a, frame.to_array());
+
+    let rf = RenderFrame::Rgb(2, 2, serialize_bytes_to_binary_stream(&pixels));
+    assert!(matches!(rf.to_rgba(), Err(GymError::MalformedJson(_))));
+
+    let rf = RenderFrame::Ansi("x".to_string());
+    assert!(matches!(rf.to_array(), Err(GymError::Unsupported(_))));
+}
+
+#[test]
+fn render_frame_png() {
+    let (pixels, rf) = frame();
+    let path = std::env::temp_dir().join(format!("render_frame_{}.png", std::process::id()));
+    rf.save_png(&path).unwrap();
+
+    let dec = png::Decoder::new(std::fs::File::open(&path).unwrap());
+    let mut reader = dec.read_info().unwrap();
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!((info.width, info.height), (3, 2));
+    assert_eq!(info.color_type, png::ColorType::Rgba);
+    assert_eq!(&buf[..info.buffer_size()], pixels);
+}
@@ -0,0 +1,71 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::defs::*;
+use gymnasium::*;
+use serde_json::to_value;
+
+#[test]
+fn discrete_space_contains_e2e() {
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        "http://127.0.0.1:40004",
+        "FrozenLake-v1",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    for a in [-1, 0, 3, 4] {
+        assert_eq!(
+            env.action_space_contains(&a).unwrap(),
+            env.action_space().contains(&a)
+        );
+    }
+
+    assert!(env
+        .observation_space_matches(&[
+            ("name", to_value("Discrete").unwrap()),
+            ("n", to_value(16).unwrap())
+        ])
+        .unwrap());
+    assert!(!env
+        .observation_space_matches(&[("n", to_value(17).unwrap())])
+        .unwrap());
+    assert!(!env
+        .observation_space_matches(&[("shape", to_value([16]).unwrap())])
+        .unwrap());
+}
+
+#[test]
+fn box_space_contains_e2e() {
+    let env = Environment::<BoxSpace<Continous>, BoxSpace<Continous>>::new(
+        "http://127.0.0.1:40004",
+        "MountainCarContinuous-v0",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    for a in [vec![0.5], vec![-1.], vec![1.5], vec![0.5, 0.5]] {
+        assert_eq!(
+            env.action_space_contains(&a).unwrap(),
+            env.action_space().contains(&a)
+        );
+    }
+
+    assert!(env
+        .observation_space_matches(&[
+            ("name", to_value("Box").unwrap()),
+            (
+                "shape",
+                to_value(env.observation_space().shape.clone()).unwrap()
+            )
+        ])
+        .unwrap());
+}
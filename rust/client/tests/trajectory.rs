@@ -0,0 +1,69 @@
+extern crate gymnasium;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::envs::*;
+use gymnasium::info::*;
+use gymnasium::*;
+use std::rc::Rc;
+
+/// Straight to the goal of the non slippery 4x4 map.
+struct Shortest;
+
+impl Policy<DiscreteSpace, DiscreteSpace> for Shortest {
+    fn policy(&self, s: &Discrete) -> GymResult<Discrete> {
+        Ok(match s {
+            0 | 4 | 9 => 1,
+            _ => 2,
+        })
+    }
+}
+
+fn frozen_lake() -> LocalEnvironment<FrozenLake> {
+    LocalEnvironment::new(FrozenLake::new(FrozenLake::map("4x4").unwrap(), false, None).unwrap())
+}
+
+#[test]
+fn trajectory_record() {
+    let mut env = frozen_lake();
+    let t = Trajectory::record(&mut env, &Shortest, Some(0), None).unwrap();
+    assert_eq!(t.len(), 6);
+    assert!(t.is_terminated());
+    assert!(!t.is_truncated());
+    assert_eq!(
+        t.iter().map(|st| st.a).collect::<Vec<_>>(),
+        [1, 1, 2, 1, 2, 2]
+    );
+    assert!(t.steps.windows(2).all(|w| w[0].s_next == w[1].s));
+    assert_eq!((t.steps[0].s, t.steps[5].s_next), (0, 15));
+    for st in &t {
+        assert_eq!(st.info_as::<TransitionInfo>().unwrap().prob, 1.);
+    }
+
+    assert_eq!(t.rewards().sum::<f64>(), 1.);
+    let returns = t.returns(0.9);
+    assert!((returns[0] - 0.9f64.powi(5)).abs() < 1e-12);
+    assert_eq!(returns[5], 1.);
+    assert_eq!(t.discounted_return(0.9), returns[0]);
+
+    let events: Vec<EpisodeEvent<Discrete>> = t.episode_events();
+    assert_eq!(events.len(), 7);
+    assert_eq!((events[0].s[0], events[0].r), (0, 0.));
+    assert_eq!((events[6].s[0], events[6].r), (15, 1.));
+}
+
+#[test]
+fn trajectory_truncated() {
+    struct Left;
+    impl Policy<DiscreteSpace, DiscreteSpace> for Left {
+        fn policy(&self, _s: &Discrete) -> GymResult<Discrete> {
+            Ok(0)
+        }
+    }
+
+    let ep_gen = PolicyEpisodeGenerator::new(frozen_lake(), Rc::new(Left), Some(3));
+    let t = ep_gen.trajectory(Some(0)).unwrap();
+    assert_eq!(t.len(), 3);
+    assert!(t.is_truncated());
+    assert!(!t.is_terminated());
+    assert_eq!(t.returns(0.9), [0., 0., 0.]);
+}
@@ -0,0 +1,58 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{error::*, wire_format::*};
+use gymnasium::envs::*;
+use gymnasium::*;
+use serde_json::to_value;
+use std::cell::Cell;
+
+fn model() -> TransitionModel {
+    let env = FrozenLake::new(FrozenLake::map("4x4").unwrap(), true, None).unwrap();
+    TransitionModel::from_native(&env).unwrap()
+}
+
+#[test]
+fn transition_model_roundtrip() {
+    let model = model();
+    for format in [WireFormat::Json, WireFormat::MessagePack] {
+        let decoded = TransitionModel::decode(format, &model.encode(format)).unwrap();
+        assert_eq!(decoded.transitions(), model.transitions());
+        assert_eq!(decoded.p(), model.p());
+    }
+    assert!(model.encode(WireFormat::MessagePack).len() < model.encode(WireFormat::Json).len());
+
+    // Checked on deserialization too.
+    let json = String::from_utf8(model.encode(WireFormat::Json)).unwrap();
+    let json = json.replacen("\"n_s\":16", "\"n_s\":17", 1);
+    assert!(matches!(
+        TransitionModel::decode(WireFormat::Json, json.as_bytes()),
+        Err(GymError::MalformedJson(_))
+    ));
+}
+
+#[test]
+fn transition_cache() {
+    let dir = std::env::temp_dir().join(format!("transition_cache_{}", std::process::id()));
+    let cache = TransitionCache::new(&dir);
+    let kwargs = [("is_slippery", to_value(true).unwrap())];
+    assert_ne!(
+        cache.path("FrozenLake-v1", &kwargs),
+        cache.path("FrozenLake-v1", &[])
+    );
+    assert!(cache.load("FrozenLake-v1", &kwargs).unwrap().is_none());
+
+    let fetched = Cell::new(0);
+    let fetch = || {
+        fetched.set(fetched.get() + 1);
+        Ok(model())
+    };
+    let m0 = cache.get_or_fetch("FrozenLake-v1", &kwargs, fetch).unwrap();
+    let m1 = cache.get_or_fetch("FrozenLake-v1", &kwargs, fetch).unwrap();
+    assert_eq!(fetched.get(), 1);
+    assert_eq!(m0.transitions(), m1.transitions());
+
+    std::fs::write(cache.path("FrozenLake-v1", &kwargs), b"garbage").unwrap();
+    assert!(cache.load("FrozenLake-v1", &kwargs).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
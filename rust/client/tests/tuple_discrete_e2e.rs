@@ -0,0 +1,35 @@
+extern crate gymnasium;
+
+use gymnasium::*;
+
+/// Refer: https://gymnasium.farama.org/environments/toy_text/blackjack/
+#[test]
+fn tuple_discrete_e2e() {
+    let env = Environment::<TupleSpace, DiscreteSpace>::new(
+        "http://127.0.0.1:40004",
+        "Blackjack-v1",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    let osvs = env.observation_space();
+    let ns = osvs
+        .spaces
+        .iter()
+        .map(|s| s.as_discrete().unwrap().n)
+        .collect::<Vec<_>>();
+    assert_eq!(ns, [32, 11, 2]);
+    assert_eq!(env.action_space().n, 2);
+
+    let s = env.reset(Some(2718), None).unwrap().observation;
+    assert_eq!(s.len(), 3);
+    assert!(s.iter().all(|x| x.as_discrete().is_some()));
+
+    let si = env.step(&0).unwrap();
+    assert_eq!(si.observation.len(), 3);
+    assert!(si.terminated);
+}
@@ -0,0 +1,75 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::error::*;
+use gymnasium::*;
+use serde_json::to_value;
+
+#[test]
+fn vec_env_e2e() {
+    let envs = VecEnvironment::<DiscreteSpace, DiscreteSpace>::new(
+        "http://127.0.0.1:40004",
+        "FrozenLake-v1",
+        3,
+        None,
+        None,
+        &[
+            ("is_slippery", to_value(false).unwrap()),
+            ("desc", to_value(["SH", "FG"]).unwrap()),
+        ],
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(envs.num_envs(), 3);
+    assert_eq!(envs.single_observation_space().n, 4);
+
+    let obs = envs
+        .reset(Some(&[2718, 2719, 2720]), None)
+        .unwrap()
+        .into_iter()
+        .map(|ri| ri.observation)
+        .collect::<Vec<_>>();
+    assert_eq!(obs, [0, 0, 0]);
+    assert!(matches!(
+        envs.reset(Some(&[2718]), None),
+        Err(GymError::InvalidArgument(_))
+    ));
+
+    // Right: into the hole, Down: onto the frozen tile, Left: against the wall.
+    let sis = envs.step(&[2, 1, 0]).unwrap();
+    assert_eq!(
+        sis.iter().map(|si| si.observation).collect::<Vec<_>>(),
+        [0, 2, 0]
+    );
+    assert!(sis[0].terminated);
+    assert_eq!(sis[0].final_observation, Some(1));
+    assert!(sis[0].final_info.is_some());
+    assert!(!sis[1].terminated);
+    assert_eq!(sis[1].final_observation, None);
+    assert_eq!(sis[2].final_observation, None);
+
+    // Right: onto the goal.
+    let sis = envs.step(&[0, 2, 0]).unwrap();
+    assert!(sis[1].terminated);
+    assert_eq!(sis[1].reward, 1.);
+    assert_eq!(sis[1].observation, 0);
+    assert_eq!(sis[1].final_observation, Some(3));
+
+    assert!(matches!(
+        envs.step(&[0, 2]),
+        Err(GymError::InvalidArgument(_))
+    ));
+}
+
+#[test]
+fn vec_env_without_sub_environments() {
+    let res = VecEnvironment::<DiscreteSpace, DiscreteSpace>::new(
+        "http://127.0.0.1:40004",
+        "FrozenLake-v1",
+        0,
+        None,
+        None,
+        &[],
+    );
+    assert!(matches!(res, Err(GymError::InvalidArgument(_))));
+}
@@ -0,0 +1,100 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, utils::*, wire_format::*};
+use gymnasium::*;
+use serde_json::json;
+
+#[test]
+fn wire_format_negotiated() {
+    assert_eq!(
+        WireFormat::from_accept(Some(WireFormat::ACCEPT)),
+        WireFormat::MessagePack
+    );
+    assert_eq!(WireFormat::from_accept(Some("*/*")), WireFormat::Json);
+    assert_eq!(WireFormat::from_accept(None), WireFormat::Json);
+
+    assert_eq!(
+        WireFormat::from_content_type(Some("application/msgpack")),
+        WireFormat::MessagePack
+    );
+    assert_eq!(
+        WireFormat::from_content_type(Some("application/json; charset=utf-8")),
+        WireFormat::Json
+    );
+    assert_eq!(WireFormat::from_content_type(None), WireFormat::Json);
+}
+
+#[test]
+fn msgpack_observations_as_raw_bytes() {
+    let pixels = [0u8, 127, 255, 1];
+    let space = BoxSpace::<u8> {
+        shape: vec![2, 2],
+        high: vec![255; 4],
+        low: vec![0; 4],
+    };
+
+    let obs = Body::object([
+        ("type", Body::from(json!("uint8"))),
+        ("shape", json!([2, 2]).into()),
+        ("data", Body::binary(pixels.to_vec())),
+    ]);
+    let res = Body::object([
+        ("observation", obs),
+        ("reward", json!(-0.5).into()),
+        // Not a binary stream, whatever its key.
+        ("info", json!({ "prob": 1, "data": [1, 0] }).into()),
+    ]);
+    let body = WireFormat::MessagePack.encode(res.clone());
+    // Raw bytes, i.e. bin 8 of 4 bytes.
+    assert!(body.windows(6).any(|w| w == [0xc4, 4, 0, 127, 255, 1]));
+    assert!(!body.windows(3).any(|w| w == [0xc4, 2, 1]));
+
+    let val = WireFormat::MessagePack.decode(&body).unwrap();
+    assert_eq!(val, res);
+    assert_eq!(
+        val.binaries.keys().collect::<Vec<_>>(),
+        ["/observation/data"]
+    );
+    assert_eq!(space.observation(&val.get("observation")).unwrap(), pixels);
+    assert_eq!(
+        val.get("observation").to_value()["data"],
+        json!([0, 127, 255, 1])
+    );
+
+    // Same observation, as sent in JSON.
+    let val = WireFormat::Json
+        .decode(&WireFormat::Json.encode(res))
+        .unwrap();
+    assert!(val.binaries.is_empty());
+    assert_eq!(
+        val.value["observation"]["data"],
+        serialize_bytes_to_binary_stream(&pixels)
+    );
+    assert_eq!(space.observation(&val.get("observation")).unwrap(), pixels);
+}
+
+#[test]
+fn binary_streams_by_json_pointer() {
+    let res = Body::object([(
+        "info",
+        Body::object([("a/b~c", Body::array([Body::binary(vec![7])]))]),
+    )]);
+    assert_eq!(res.binaries.keys().collect::<Vec<_>>(), ["/info/a~1b~0c/0"]);
+
+    let val = WireFormat::MessagePack
+        .decode(&WireFormat::MessagePack.encode(res.clone()))
+        .unwrap();
+    assert_eq!(val, res);
+    assert_eq!(val.get("info").to_value(), json!({ "a/b~c": [[7]] }));
+}
+
+#[test]
+fn msgpack_integer_keys_as_strings() {
+    // E.g. the transitions of gym_http_server.py, whose states and actions are ints.
+    let body = [0x81, 0x05, 0x81, 0x01, 0x90];
+    assert_eq!(
+        WireFormat::MessagePack.decode(&body).unwrap().value,
+        json!({ "5": { "1": [] } })
+    );
+}
@@ -0,0 +1,191 @@
+extern crate gymnasium;
+extern crate serde_json;
+
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::envs::*;
+use gymnasium::wrappers::*;
+use gymnasium::*;
+use serde_json::{Map, Value};
+
+struct Constant(Continous);
+
+impl Policy<BoxSpace<Continous>, BoxSpace<Continous>> for Constant {
+    fn policy(&self, _s: &Vec<Continous>) -> GymResult<Vec<Continous>> {
+        Ok(vec![self.0])
+    }
+}
+
+#[test]
+fn time_limit_and_episode_statistics() {
+    let env = LocalEnvironment::new(Pendulum::new(10.));
+    let mut env = RecordEpisodeStatistics::new(TimeLimit::new(env, 10), Some(2));
+
+    for seed in 0..3 {
+        let (_, steps) = rollout(&mut env, &Constant(0.), Some(seed)).unwrap();
+        assert_eq!(steps.len(), 10);
+        assert!(steps.last().unwrap().truncated);
+        assert!(steps[..9].iter().all(|si| si.info.get("episode").is_none()));
+
+        let episode = &steps.last().unwrap().info["episode"];
+        let r = steps.iter().map(|si| si.reward).sum::<f64>();
+        assert_eq!(episode["l"], 10);
+        assert!((episode["r"].as_f64().unwrap() - r).abs() < 1e-9);
+    }
+    assert_eq!(env.length_queue(), &[10, 10]);
+    assert_eq!(env.return_queue().len(), 2);
+}
+
+/// Resets the inner environment in the same step it terminates or truncates, same as a served auto_reset one.
+struct AutoReset<E: Env>(E);
+
+impl<E: Env> Env for AutoReset<E> {
+    type O = E::O;
+    type A = E::A;
+
+    fn name(&self) -> GymResult<String> {
+        self.0.name()
+    }
+
+    fn observation_space(&self) -> &E::O {
+        self.0.observation_space()
+    }
+
+    fn action_space(&self) -> &E::A {
+        self.0.action_space()
+    }
+
+    fn reset(
+        &mut self,
+        seed: Option<u64>,
+        options: Option<&Map<String, Value>>,
+    ) -> GymResult<ResetInfo<E::O>> {
+        self.0.reset(seed, options)
+    }
+
+    fn step(&mut self, action: &<E::A as Space>::Item) -> GymResult<StepInfo<E::O>> {
+        let si = self.0.step(action)?;
+        if si.terminated || si.truncated {
+            self.0.reset(None, None)?;
+        }
+
+        Ok(si)
+    }
+
+    fn render(&self) -> GymResult<RenderFrame> {
+        self.0.render()
+    }
+
+    fn close(&mut self) -> GymResult<()> {
+        self.0.close()
+    }
+}
+
+#[test]
+fn episode_statistics_with_auto_reset() {
+    let env = AutoReset(TimeLimit::new(LocalEnvironment::new(Pendulum::new(10.)), 5));
+    let mut env = RecordEpisodeStatistics::new(env, None);
+
+    env.reset(Some(0), None).unwrap();
+    for _ in 0..3 {
+        let steps = (0..5)
+            .map(|_| env.step(&vec![0.]).unwrap())
+            .collect::<Vec<_>>();
+        let r = steps.iter().map(|si| si.reward).sum::<f64>();
+        let episode = &steps.last().unwrap().info["episode"];
+        assert_eq!(episode["l"], 5);
+        assert!((episode["r"].as_f64().unwrap() - r).abs() < 1e-9);
+    }
+    assert_eq!(env.length_queue(), &[5, 5, 5]);
+}
+
+#[test]
+fn rescale_and_clip_action() {
+    let mut env = RescaleAction::new(LocalEnvironment::new(Pendulum::new(10.)), -1., 1.).unwrap();
+    assert_eq!(env.action_space().low, [-1.]);
+    assert_eq!(env.action_space().high, [1.]);
+
+    let mut unwrapped = LocalEnvironment::new(Pendulum::new(10.));
+    assert_eq!(
+        env.reset(Some(42), None).unwrap().observation,
+        unwrapped.reset(Some(42), None).unwrap().observation
+    );
+    assert_eq!(
+        env.step(&vec![0.5]).unwrap().observation,
+        unwrapped.step(&vec![1.]).unwrap().observation
+    );
+
+    let mut env = ClipAction::new(LocalEnvironment::new(Pendulum::new(10.)));
+    assert!(env.action_space().contains(&vec![100.]));
+    env.reset(Some(42), None).unwrap();
+    unwrapped.reset(Some(42), None).unwrap();
+    assert_eq!(
+        env.step(&vec![100.]).unwrap().observation,
+        unwrapped.step(&vec![2.]).unwrap().observation
+    );
+
+    for (min, max) in [(1., 1.), (1., -1.), (f64::NEG_INFINITY, 1.)] {
+        let res = RescaleAction::new(LocalEnvironment::new(Pendulum::new(10.)), min, max);
+        assert!(matches!(res, Err(GymError::InvalidArgument(_))));
+    }
+}
+
+#[test]
+fn transform_observation_and_reward() {
+    // Which side the pole leans to.
+    let env = TransformObservation::with_space(
+        LocalEnvironment::new(CartPole::new()),
+        DiscreteSpace { n: 2 },
+        |s: Vec<Continous>| (s[2] > 0.) as Discrete,
+    );
+    let mut env = TransformReward::new(env, |r| -r);
+    assert_eq!(env.observation_space().n, 2);
+
+    assert_eq!(env.reset(Some(42), None).unwrap().observation, 1);
+    let si = env.step(&1).unwrap();
+    assert!(env.observation_space().contains(&si.observation));
+    assert_eq!(si.reward, -1.);
+
+    let mut env = TransformObservation::new(LocalEnvironment::new(CartPole::new()), |s| {
+        s.iter().map(|x| 2. * x).collect()
+    });
+    let s = env.reset(Some(42), None).unwrap().observation;
+    assert!((s[0] - 2. * 0.0273956).abs() < 1e-6);
+}
+
+#[test]
+fn normalize_observation() {
+    let mut env = NormalizeObservation::new(LocalEnvironment::new(CartPole::new()), None);
+    assert!(env.observation_space().low.iter().all(|l| l.is_infinite()));
+
+    let mut unwrapped = LocalEnvironment::new(CartPole::new());
+    let s = unwrapped.reset(Some(42), None).unwrap().observation;
+    env.reset(Some(42), None).unwrap();
+    let (mean, var) = env.mean_var();
+    assert!(mean.iter().zip(&s).all(|(m, x)| (m - x).abs() < 1e-5));
+    assert!(var.iter().all(|v| *v < 1e-3));
+
+    for _ in 0..5 {
+        let si = env.step(&0).unwrap();
+        assert!(si.observation.iter().all(|x| x.is_finite()));
+    }
+}
+
+#[test]
+fn frame_stack() {
+    let res = FrameStack::new(LocalEnvironment::new(CartPole::new()), 0);
+    assert!(matches!(res, Err(GymError::InvalidArgument(_))));
+
+    let mut env = FrameStack::new(LocalEnvironment::new(CartPole::new()), 3).unwrap();
+    assert!(matches!(env.step(&1), Err(GymError::InvalidArgument(_))));
+    assert_eq!(env.observation_space().shape, [3, 4]);
+    assert_eq!(env.observation_space().low.len(), 12);
+
+    let mut unwrapped = LocalEnvironment::new(CartPole::new());
+    let s0 = unwrapped.reset(Some(42), None).unwrap().observation;
+    assert_eq!(env.reset(Some(42), None).unwrap().observation, s0.repeat(3));
+
+    let s1 = unwrapped.step(&1).unwrap().observation;
+    let si = env.step(&1).unwrap();
+    assert!(env.observation_space().contains(&si.observation));
+    assert_eq!(si.observation, [&s0[..], &s0, &s1].concat());
+}
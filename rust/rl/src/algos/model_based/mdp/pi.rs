@@ -1,5 +1,6 @@
 use super::common;
 use super::*;
+use gymnasium::common::error::*;
 use gymnasium::*;
 use std::rc::Rc;
 
@@ -35,7 +36,7 @@ impl MdpSolver<bool> for PolicyIteration {
 
         let mut iter_done = 0;
         let mut policy_stable = false;
-        for i in 0..num_iterations.unwrap_or(usize::max_value()) {
+        for i in 0..num_iterations.unwrap_or(usize::MAX) {
             iter_done = i;
             self.policy_evaluation(theta);
             policy_stable = self.policy_improvement();
@@ -49,8 +50,9 @@ impl MdpSolver<bool> for PolicyIteration {
 }
 
 impl Policy<DiscreteSpace, DiscreteSpace> for PolicyIteration {
-    fn policy(&self, s: &Discrete) -> Discrete {
-        self.pi_star(*s).unwrap()
+    fn policy(&self, s: &Discrete) -> GymResult<Discrete> {
+        self.pi_star(*s)
+            .ok_or_else(|| GymError::InvalidArgument(format!("no action for state {s}")))
     }
 }
 
@@ -1,5 +1,6 @@
 use super::common;
 use super::*;
+use gymnasium::common::error::*;
 use gymnasium::*;
 use std::rc::Rc;
 
@@ -35,7 +36,7 @@ impl MdpSolver<f32> for ValueIteration {
         self.values_prev.fill(self.v_init);
         let mut delta = 0.;
         let mut iter_done = 0;
-        for i in 0..num_iterations.unwrap_or(usize::max_value()) {
+        for i in 0..num_iterations.unwrap_or(usize::MAX) {
             iter_done = i;
             self.values_prev.copy_from_slice(&self.values);
             self.values.fill(self.v_init);
@@ -51,8 +52,9 @@ impl MdpSolver<f32> for ValueIteration {
 }
 
 impl Policy<DiscreteSpace, DiscreteSpace> for ValueIteration {
-    fn policy(&self, s: &Discrete) -> Discrete {
-        self.pi_star(*s).unwrap()
+    fn policy(&self, s: &Discrete) -> GymResult<Discrete> {
+        self.pi_star(*s)
+            .ok_or_else(|| GymError::InvalidArgument(format!("no action for state {s}")))
     }
 }
 
@@ -1,4 +1,5 @@
 use gymnasium::common::defs::{Continous, Discrete, EpisodeEvent, EpisodeGenerator};
+use gymnasium::common::error::GymResult;
 use std::iter::zip;
 use std::rc::Rc;
 
@@ -9,7 +10,7 @@ pub fn mc_first_visit(
     gamma: Continous,
     n_s: usize,
     n_ep: usize,
-) -> Vec<Continous> {
+) -> GymResult<Vec<Continous>> {
     mc_first_core(ep_gen, gamma, n_s, n_ep, is_first_visit)
 }
 
@@ -21,7 +22,7 @@ pub fn mc_every_visit(
     gamma: Continous,
     n_s: usize,
     n_ep: usize,
-) -> Vec<Continous> {
+) -> GymResult<Vec<Continous>> {
     mc_first_core(ep_gen, gamma, n_s, n_ep, |_, _, _| true)
 }
 
@@ -32,11 +33,11 @@ pub fn mc_first_core(
     n_s: usize,
     n_ep: usize,
     is_first_visit: fn(&[EpisodeEvent<Discrete>], usize, Discrete) -> bool,
-) -> Vec<Continous> {
+) -> GymResult<Vec<Continous>> {
     let returns = &mut vec![0 as Continous; n_s];
     let visits = &mut vec![0 as Discrete; n_s];
 
-    let eps = ep_gen.generate(n_ep, None);
+    let eps = ep_gen.generate(n_ep, None)?;
     for ep in eps.iter().take(n_ep) {
         let mut g = 0.;
         for t in (0..(ep.len() - 1)).rev() {
@@ -48,9 +49,9 @@ pub fn mc_first_core(
         }
     }
 
-    zip(returns, visits)
+    Ok(zip(returns, visits)
         .map(|(&mut r, &mut v)| if v == 0 { 0. } else { r / (v as Continous) })
-        .collect()
+        .collect())
 }
 
 fn is_first_visit(ep: &[EpisodeEvent<Discrete>], t: usize, s: Discrete) -> bool {
@@ -65,14 +66,33 @@ fn is_first_visit(ep: &[EpisodeEvent<Discrete>], t: usize, s: Discrete) -> bool
 mod tests {
     use super::*;
     use float_eq::*;
+    use gymnasium::common::defs::*;
+    use gymnasium::envs::FrozenLake;
+    use gymnasium::{DiscreteSpace, LocalEnvironment, PolicyEpisodeGenerator};
+
+    /// Down, down, right, down, right, right, i.e. straight to the goal of the non slippery 4x4 map.
+    struct Shortest;
+
+    impl Policy<DiscreteSpace, DiscreteSpace> for Shortest {
+        fn policy(&self, s: &Discrete) -> GymResult<Discrete> {
+            Ok(match s {
+                0 | 4 | 9 => 1,
+                _ => 2,
+            })
+        }
+    }
 
     struct SimpleEnv {
         pub episodes: Vec<Vec<EpisodeEvent<Discrete>>>,
     }
 
     impl EpisodeGenerator<Discrete> for SimpleEnv {
-        fn generate(&self, _n: usize, _seed: Option<usize>) -> Vec<Vec<EpisodeEvent<Discrete>>> {
-            self.episodes.clone()
+        fn generate(
+            &self,
+            _n: usize,
+            _seed: Option<usize>,
+        ) -> GymResult<Vec<Vec<EpisodeEvent<Discrete>>>> {
+            Ok(self.episodes.clone())
         }
     }
 
@@ -98,7 +118,7 @@ mod tests {
             ],
         };
 
-        let v = mc_first_visit(Rc::new(ep_gen), 0.9, 6, 3);
+        let v = mc_first_visit(Rc::new(ep_gen), 0.9, 6, 3).unwrap();
 
         assert_float_eq!(
             v,
@@ -129,7 +149,7 @@ mod tests {
             ],
         };
 
-        let v = mc_every_visit(Rc::new(ep_gen), 0.9, 6, 3);
+        let v = mc_every_visit(Rc::new(ep_gen), 0.9, 6, 3).unwrap();
 
         assert_float_eq!(
             v,
@@ -144,4 +164,19 @@ mod tests {
             abs_all <= 1e-5
         );
     }
+
+    #[test]
+    fn policy_episodes_with_first_visit() {
+        let env = FrozenLake::new(FrozenLake::map("4x4").unwrap(), false, None).unwrap();
+        let ep_gen =
+            PolicyEpisodeGenerator::new(LocalEnvironment::new(env), Rc::new(Shortest), Some(100));
+
+        let v = mc_first_visit(Rc::new(ep_gen), 0.9, 16, 2).unwrap();
+
+        let path = [0, 4, 8, 9, 13, 14];
+        for (k, s) in path.iter().rev().enumerate() {
+            assert_float_eq!(v[*s], 0.9f64.powi(k as i32), abs <= 1e-12);
+        }
+        assert_float_eq!(v[15], 0., abs <= 1e-12);
+    }
 }
@@ -2,6 +2,7 @@ pub mod gradient_free;
 
 use gymnasium::common::defs::*;
 
+#[allow(dead_code)]
 pub trait MdpSimulator {
     fn name(&self) -> String;
 
@@ -4,30 +4,31 @@ use gymnasium::*;
 use std::rc::Rc;
 
 pub struct GymAdapter {
-    env: Rc<Environment<DiscreteSpace, DiscreteSpace>>,
+    n_s: usize,
+    n_a: usize,
     gamma: f32,
     transitions: Rc<Transitions>,
 }
 
 impl GymAdapter {
-    pub fn new(env: Rc<Environment<DiscreteSpace, DiscreteSpace>>, gamma: f32) -> Self {
-        let transitions = transitions(&env);
-
+    /// E.g. transition_model(&env), or offline from TransitionCache::load or TransitionCache::get_or_fetch.
+    pub fn from_model(model: TransitionModel, gamma: f32) -> Self {
         Self {
-            env,
+            n_s: model.n_s() as usize,
+            n_a: model.n_a() as usize,
             gamma,
-            transitions,
+            transitions: Rc::new(model.into_transitions()),
         }
     }
 }
 
 impl Mdp for GymAdapter {
     fn n_s(&self) -> usize {
-        self.env.observation_space().n as usize
+        self.n_s
     }
 
     fn n_a(&self) -> usize {
-        self.env.action_space().n as usize
+        self.n_a
     }
 
     fn transitions(&self) -> Rc<Transitions> {
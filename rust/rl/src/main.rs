@@ -10,22 +10,31 @@ use serde_json::to_value;
 use std::rc::Rc;
 
 fn main() -> ui::Result {
-    let env = Environment::new(
+    let env_id = "FrozenLake-v1";
+    let kwargs = [
+        ("render_mode", to_value("rgb_array").unwrap()),
+        ("map_name", to_value("8x8").unwrap()),
+    ];
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
         "http://127.0.0.1:40004",
-        "FrozenLake-v1",
+        env_id,
         None,
         None,
         None,
-        &[
-            ("render_mode", to_value("rgb_array").unwrap()),
-            ("map_name", to_value("8x8").unwrap()),
-        ],
+        &kwargs,
     )
+    .unwrap()
+    .close_on_drop()
     .rc();
     let base_url = env.client_base_url().to_string();
     let instance_id = env.instance_id().to_string();
 
-    let ga = Rc::new(GymAdapter::new(Rc::clone(&env), 0.9));
+    // NOTE: Fetched from the server on the first run only.
+    let cache = TransitionCache::new(std::env::temp_dir().join("gymnasium-transitions"));
+    let model = cache
+        .get_or_fetch(env_id, &kwargs, || transition_model(&env))
+        .unwrap();
+    let ga = Rc::new(GymAdapter::from_model(model, 0.9));
     let mdp = ga as Rc<dyn Mdp>;
     let theta = 1e-8;
     let pi = &mut PolicyIteration::new(Rc::clone(&mdp), 0., 0);
@@ -53,5 +62,5 @@ fn main() -> ui::Result {
     println!("{q_star:?}");
 
     let policy = Rc::new(pi.clone());
-    ui::GymnasiumApp::run(&base_url, &instance_id, None, policy)
+    ui::GymnasiumApp::run(&base_url, &instance_id, None, None, policy)
 }
@@ -0,0 +1,363 @@
+use crate::wire::*;
+use crate::InvalidUsage;
+use gymnasium::common::{defs::*, wire_format::Body};
+use gymnasium::envs::*;
+use gymnasium::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde_json::{json, Map, Value};
+
+/// NativeEnvironment with its spaces and items erased to their wire representation.
+pub trait ServerEnv: Send {
+    fn observation_space_info(&self) -> Result<Value, InvalidUsage>;
+
+    fn action_space_info(&self) -> Result<Value, InvalidUsage>;
+
+    fn reset(&mut self, seed: Option<u64>) -> Result<ResetResult, InvalidUsage>;
+
+    fn step(&mut self, action: &Value) -> Result<StepResult, InvalidUsage>;
+
+    fn render(&self) -> Body;
+
+    fn action_space_sample(&mut self) -> Result<Value, InvalidUsage>;
+
+    fn action_space_contains(&self, action: &Value) -> bool;
+
+    fn transitions(&self) -> Option<Value>;
+}
+
+#[derive(Debug)]
+pub struct ResetResult {
+    pub observation: Body,
+    pub info: Value,
+}
+
+#[derive(Debug)]
+pub struct StepResult {
+    pub observation: Body,
+    pub reward: f64,
+    pub terminated: bool,
+    pub truncated: bool,
+    pub info: Body,
+}
+
+/// Creates the environment from the kwargs of gymnasium.make.
+pub type MakeEnv = fn(&Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage>;
+
+/// Registered environment ids, same as gymnasium.envs.registration.
+pub struct EnvSpec {
+    pub id: &'static str,
+    pub max_episode_steps: Option<usize>,
+    pub make: MakeEnv,
+}
+
+pub const REGISTRY: &[EnvSpec] = &[
+    EnvSpec {
+        id: "CartPole-v1",
+        max_episode_steps: Some(500),
+        make: make_cartpole,
+    },
+    EnvSpec {
+        id: "MountainCar-v0",
+        max_episode_steps: Some(200),
+        make: make_mountain_car,
+    },
+    EnvSpec {
+        id: "MountainCarContinuous-v0",
+        max_episode_steps: Some(999),
+        make: make_continuous_mountain_car,
+    },
+    EnvSpec {
+        id: "Pendulum-v1",
+        max_episode_steps: Some(200),
+        make: make_pendulum,
+    },
+    EnvSpec {
+        id: "Acrobot-v1",
+        max_episode_steps: Some(500),
+        make: make_acrobot,
+    },
+    EnvSpec {
+        id: "Blackjack-v1",
+        max_episode_steps: None,
+        make: make_blackjack,
+    },
+    EnvSpec {
+        id: "FrozenLake-v1",
+        max_episode_steps: Some(100),
+        make: make_frozen_lake,
+    },
+    EnvSpec {
+        id: "CliffWalking-v0",
+        max_episode_steps: None,
+        make: make_cliff_walking,
+    },
+    EnvSpec {
+        id: "Taxi-v3",
+        max_episode_steps: Some(200),
+        make: make_taxi,
+    },
+];
+
+pub fn spec(env_id: &str) -> Option<&'static EnvSpec> {
+    REGISTRY.iter().find(|s| s.id == env_id)
+}
+
+struct Native<E: NativeEnvironment> {
+    env: E,
+    /// NOTE: Same as gymnasium, the action space is sampled independently of the environment's seed.
+    rng: StdRng,
+}
+
+impl<E: NativeEnvironment + Send + 'static> Native<E>
+where
+    E::O: WireSpace,
+    E::A: WireSpace,
+{
+    fn boxed(env: E) -> Box<dyn ServerEnv> {
+        Box::new(Self {
+            env,
+            rng: StdRng::from_entropy(),
+        })
+    }
+}
+
+impl<E: NativeEnvironment + Send> ServerEnv for Native<E>
+where
+    E::O: WireSpace,
+    E::A: WireSpace,
+{
+    fn observation_space_info(&self) -> Result<Value, InvalidUsage> {
+        self.env.observation_space().info()
+    }
+
+    fn action_space_info(&self) -> Result<Value, InvalidUsage> {
+        self.env.action_space().info()
+    }
+
+    fn reset(&mut self, seed: Option<u64>) -> Result<ResetResult, InvalidUsage> {
+        let ri = self.env.reset(seed);
+        Ok(ResetResult {
+            observation: self
+                .env
+                .observation_space()
+                .observation_to_body(&ri.observation)?,
+            info: ri.info,
+        })
+    }
+
+    fn step(&mut self, action: &Value) -> Result<StepResult, InvalidUsage> {
+        let act_space = self.env.action_space();
+        let action = act_space
+            .action(action)
+            .ok()
+            .filter(|a| act_space.contains(a))
+            .ok_or_else(|| InvalidUsage::new(format!("Invalid action {action}")))?;
+
+        let si = self.env.step(&action);
+        Ok(StepResult {
+            observation: self
+                .env
+                .observation_space()
+                .observation_to_body(&si.observation)?,
+            reward: si.reward,
+            terminated: si.terminated,
+            truncated: si.truncated,
+            info: si.info.into(),
+        })
+    }
+
+    fn render(&self) -> Body {
+        match self.env.render() {
+            Some(RenderFrame::Ansi(s)) => Value::String(s).into(),
+            Some(RenderFrame::Rgb(rows, cols, data)) => {
+                json!({ "rows": rows, "cols": cols, "data": data }).into()
+            }
+            Some(RenderFrame::Rgba(frame)) => Body::object([
+                ("rows", json!(frame.height()).into()),
+                ("cols", json!(frame.width()).into()),
+                ("data", Body::binary(frame.pixels().to_vec())),
+            ]),
+            None => Value::Null.into(),
+        }
+    }
+
+    fn action_space_sample(&mut self) -> Result<Value, InvalidUsage> {
+        let act_space = self.env.action_space();
+        let action = act_space
+            .sample(&mut self.rng)
+            .map_err(|e| not_served(e.to_string()))?;
+        Ok(action_to_value(act_space, &action))
+    }
+
+    fn action_space_contains(&self, action: &Value) -> bool {
+        let act_space = self.env.action_space();
+        act_space
+            .action(action)
+            .is_ok_and(|a| act_space.contains(&a))
+    }
+
+    fn transitions(&self) -> Option<Value> {
+        // Same as env.unwrapped.P, i.e. { s: { a: [[probability, next_state, reward, done], ...] } }.
+        let mut obj = Map::new();
+        for ((s, a), ts) in self.env.transitions()? {
+            let ts = ts
+                .iter()
+                .map(|t| json!([t.probability, t.next_state, t.reward, t.done]))
+                .collect();
+            obj.entry(s.to_string())
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()?
+                .insert(a.to_string(), Value::Array(ts));
+        }
+
+        Some(Value::Object(obj))
+    }
+}
+
+fn make_cartpole(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(CartPole::new()))
+}
+
+fn make_mountain_car(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode", "goal_velocity"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(MountainCar::new(float_kwarg(
+        kwargs,
+        "goal_velocity",
+        0.,
+    )?)))
+}
+
+fn make_continuous_mountain_car(
+    kwargs: &Map<String, Value>,
+) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode", "goal_velocity"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(MountainCarContinuous::new(float_kwarg(
+        kwargs,
+        "goal_velocity",
+        0.,
+    )?)))
+}
+
+fn make_pendulum(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode", "g"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(Pendulum::new(float_kwarg(kwargs, "g", 10.)?)))
+}
+
+fn make_acrobot(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(Acrobot::new()))
+}
+
+fn make_frozen_lake(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode", "desc", "map_name", "is_slippery"])?;
+
+    let desc = match (kwargs.get("desc"), kwargs.get("map_name")) {
+        (Some(desc), _) if !desc.is_null() => desc
+            .as_array()
+            .and_then(|rows| {
+                rows.iter()
+                    .map(|r| r.as_str().map(str::to_string))
+                    .collect()
+            })
+            .ok_or_else(|| InvalidUsage::new(format!("Invalid desc {desc}")))?,
+        (_, Some(Value::Null)) => {
+            FrozenLake::generate_random_map(8, 0.8, &mut StdRng::from_entropy())
+        }
+        (_, map_name) => {
+            let map_name = map_name.and_then(Value::as_str).unwrap_or("4x4");
+            FrozenLake::map(map_name)
+                .ok_or_else(|| InvalidUsage::new(format!("Unknown map_name '{map_name}'")))?
+                .iter()
+                .map(|r| r.to_string())
+                .collect::<Vec<_>>()
+        }
+    };
+    let is_slippery = bool_kwarg(kwargs, "is_slippery", true)?;
+
+    let env = FrozenLake::new(&desc, is_slippery, render_mode(kwargs)?)
+        .map_err(|e| InvalidUsage::new(e.to_string()))?;
+
+    Ok(Native::boxed(env))
+}
+
+fn make_blackjack(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode", "natural", "sab"])?;
+    no_render_mode(kwargs)?;
+
+    Ok(Native::boxed(Blackjack::new(
+        bool_kwarg(kwargs, "natural", false)?,
+        bool_kwarg(kwargs, "sab", true)?,
+    )))
+}
+
+fn make_cliff_walking(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode"])?;
+
+    Ok(Native::boxed(CliffWalking::new(render_mode(kwargs)?)))
+}
+
+fn make_taxi(kwargs: &Map<String, Value>) -> Result<Box<dyn ServerEnv>, InvalidUsage> {
+    check_kwargs(kwargs, &["render_mode"])?;
+
+    Ok(Native::boxed(Taxi::new(render_mode(kwargs)?)))
+}
+
+fn check_kwargs(kwargs: &Map<String, Value>, allowed: &[&str]) -> Result<(), InvalidUsage> {
+    match kwargs.keys().find(|k| !allowed.contains(&k.as_str())) {
+        Some(k) => Err(InvalidUsage::new(format!(
+            "Unexpected keyword argument '{k}'"
+        ))),
+        None => Ok(()),
+    }
+}
+
+fn bool_kwarg(kwargs: &Map<String, Value>, key: &str, default: bool) -> Result<bool, InvalidUsage> {
+    match kwargs.get(key) {
+        None | Some(Value::Null) => Ok(default),
+        Some(v) => v
+            .as_bool()
+            .ok_or_else(|| InvalidUsage::new(format!("Invalid {key} {v}"))),
+    }
+}
+
+/// NOTE: Booleans are accepted as 0 and 1, same as in Python.
+fn float_kwarg(kwargs: &Map<String, Value>, key: &str, default: f64) -> Result<f64, InvalidUsage> {
+    match kwargs.get(key) {
+        None | Some(Value::Null) => Ok(default),
+        Some(v) => v
+            .as_f64()
+            .or_else(|| v.as_bool().map(|b| b as u8 as f64))
+            .ok_or_else(|| InvalidUsage::new(format!("Invalid {key} {v}"))),
+    }
+}
+
+/// For the environments that render with pygame only, which the server does not support.
+fn no_render_mode(kwargs: &Map<String, Value>) -> Result<(), InvalidUsage> {
+    match kwargs.get("render_mode") {
+        None | Some(Value::Null) => Ok(()),
+        Some(v) => Err(InvalidUsage::new(format!("Unsupported render_mode {v}"))),
+    }
+}
+
+fn render_mode(kwargs: &Map<String, Value>) -> Result<Option<RenderMode>, InvalidUsage> {
+    match kwargs.get("render_mode") {
+        None | Some(Value::Null) => Ok(None),
+        Some(v) => v
+            .as_str()
+            .and_then(RenderMode::from_name)
+            .map(Some)
+            .ok_or_else(|| InvalidUsage::new(format!("Unsupported render_mode {v}"))),
+    }
+}
@@ -0,0 +1,419 @@
+extern crate gymnasium;
+extern crate rand;
+extern crate serde_json;
+extern crate tiny_http;
+
+pub mod envs;
+pub mod wire;
+
+use envs::*;
+use gymnasium::common::wire_format::{Body, WireFormat};
+use rand::Rng;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::net::{SocketAddr, ToSocketAddrs};
+use tiny_http::{Header, Method, Request, Response};
+
+/// Same protocol as gym_http_server.py, backed by the native environments of the gymnasium crate,
+/// i.e. the gymnasium client can be exercised end to end without Python.
+pub struct Server {
+    http: tiny_http::Server,
+    envs: Envs,
+}
+
+impl Server {
+    pub fn http(addr: impl ToSocketAddrs) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        Ok(Self {
+            http: tiny_http::Server::http(addr)?,
+            envs: Envs::default(),
+        })
+    }
+
+    pub fn server_addr(&self) -> SocketAddr {
+        // NOTE: Always an IP address, as created via Server::http.
+        self.http.server_addr().to_ip().unwrap()
+    }
+
+    /// Serve requests, one at a time, until the process exits.
+    pub fn run(self) {
+        let Self { http, mut envs } = self;
+        for mut req in http.incoming_requests() {
+            let accept = req
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Accept"))
+                .map(|h| h.value.to_string());
+            let format = WireFormat::from_accept(accept.as_deref());
+
+            let res = match handle(&mut envs, &mut req) {
+                Ok(Some(body)) => Response::from_data(format.encode(body)),
+                Ok(None) => Response::from_data(vec![]),
+                Err(e) => {
+                    let body = json!({ "message": e.message });
+                    Response::from_data(format.encode(body.into())).with_status_code(e.status_code)
+                }
+            };
+            let res =
+                res.with_header(Header::from_bytes("Content-Type", format.mime_type()).unwrap());
+
+            // NOTE: Nothing to be done if the client is gone.
+            let _ = req.respond(res);
+        }
+    }
+}
+
+/// Same as InvalidUsage of gym_http_server.py, i.e. 400 with the reason in the 'message' field.
+#[derive(Debug)]
+pub struct InvalidUsage {
+    pub message: String,
+    pub status_code: u16,
+}
+
+impl InvalidUsage {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            status_code: 400,
+        }
+    }
+}
+
+impl fmt::Display for InvalidUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.status_code, self.message)
+    }
+}
+
+impl Error for InvalidUsage {}
+
+/// Environment instance as created by gymnasium.make, i.e. with the TimeLimit and autoreset wrappers.
+struct Instance {
+    env_id: String,
+    env: Box<dyn ServerEnv>,
+    max_episode_steps: Option<usize>,
+    auto_reset: bool,
+    elapsed_steps: Option<usize>,
+}
+
+impl Instance {
+    fn reset(&mut self, seed: Option<u64>) -> Result<ResetResult, InvalidUsage> {
+        self.elapsed_steps = Some(0);
+        self.env.reset(seed)
+    }
+
+    fn step(&mut self, action: &Value) -> Result<StepResult, InvalidUsage> {
+        let elapsed_steps = self.elapsed_steps.as_mut().ok_or_else(|| {
+            InvalidUsage::new("Cannot call env.step() before calling env.reset()".to_string())
+        })?;
+
+        let mut sr = self.env.step(action)?;
+        *elapsed_steps += 1;
+        if self
+            .max_episode_steps
+            .is_some_and(|max| *elapsed_steps >= max)
+        {
+            sr.truncated = true;
+        }
+
+        if self.auto_reset && (sr.terminated || sr.truncated) {
+            // Same as gymnasium's AutoResetWrapper, i.e. the reset info along with the final observation and info.
+            let rr = self.reset(None)?;
+            let final_observation = std::mem::replace(&mut sr.observation, rr.observation);
+            let final_info = std::mem::take(&mut sr.info);
+            let reset_info = match rr.info {
+                Value::Object(obj) => obj,
+                _ => Map::new(),
+            };
+            sr.info = Body::object(reset_info.into_iter().map(|(k, v)| (k, v.into())).chain([
+                ("final_observation".to_string(), final_observation),
+                ("final_info".to_string(), final_info),
+            ]));
+        }
+
+        Ok(sr)
+    }
+}
+
+#[derive(Default)]
+struct Envs {
+    envs: HashMap<String, Instance>,
+}
+
+impl Envs {
+    const ID_LEN: usize = 8;
+
+    fn lookup_env(&mut self, instance_id: &str) -> Result<&mut Instance, InvalidUsage> {
+        self.envs
+            .get_mut(instance_id)
+            .ok_or_else(|| InvalidUsage::new(format!("Instance_id {instance_id} unknown")))
+    }
+
+    fn create(
+        &mut self,
+        env_id: &str,
+        max_episode_steps: Option<usize>,
+        auto_reset: bool,
+        kwargs: &Map<String, Value>,
+    ) -> Result<String, InvalidUsage> {
+        let spec = spec(env_id).ok_or_else(|| {
+            InvalidUsage::new(format!(
+                "Attempted to look up malformed environment ID '{env_id}'"
+            ))
+        })?;
+
+        let instance = Instance {
+            env_id: env_id.to_string(),
+            env: (spec.make)(kwargs)?,
+            max_episode_steps: max_episode_steps.or(spec.max_episode_steps),
+            auto_reset,
+            elapsed_steps: None,
+        };
+
+        let mut rng = rand::thread_rng();
+        let instance_id: String = (0..Self::ID_LEN)
+            .map(|_| char::from_digit(rng.gen_range(0..16), 16).unwrap())
+            .collect();
+        self.envs.insert(instance_id.clone(), instance);
+
+        Ok(instance_id)
+    }
+
+    fn get_observation_space_contains(
+        &mut self,
+        instance_id: &str,
+        j: &Map<String, Value>,
+    ) -> Result<bool, InvalidUsage> {
+        let info = self.lookup_env(instance_id)?.env.observation_space_info()?;
+
+        Ok(j.iter().all(|(k, v)| info.get(k) == Some(v)))
+    }
+
+    fn get_episode_samples(
+        &mut self,
+        instance_id: &str,
+        seed: Option<u64>,
+        count: usize,
+    ) -> Result<Body, InvalidUsage> {
+        let env = self.lookup_env(instance_id)?;
+        let event = |s, r: f64| Body::object([("s", s), ("r", json!(r).into())]);
+        let mut eps = vec![];
+        for _ in 0..count {
+            let obs = env.reset(seed)?.observation;
+            let mut ep = vec![event(obs, 0.0)];
+            loop {
+                let a = env.env.action_space_sample()?;
+                let sr = env.step(&a)?;
+                // NOTE: Truncation ends the episode too, or episodes that never terminate would never end.
+                let done = sr.terminated || sr.truncated;
+                ep.push(event(sr.observation, sr.reward));
+                if done {
+                    break;
+                }
+            }
+            eps.push(Body::array(ep));
+        }
+
+        Ok(Body::array(eps))
+    }
+}
+
+/// Routes of gym_http_server.py. Ok(None) for an empty body.
+fn handle(envs: &mut Envs, req: &mut Request) -> Result<Option<Body>, InvalidUsage> {
+    let url = req.url().split('?').next().unwrap_or_default().to_string();
+    let path = url
+        .strip_prefix("/v1/envs/")
+        .ok_or_else(not_found)?
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(percent_decode)
+        .collect::<Vec<_>>();
+    let path = path.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let method = req.method().clone();
+    let body = read_json(req);
+
+    let ret = match (method, &path[..]) {
+        (Method::Post, []) => {
+            let json_ = body?;
+            let env_id = get_required_param(&json_, "env_id")?;
+            let env_id = env_id
+                .as_str()
+                .ok_or_else(|| InvalidUsage::new(format!("Invalid env_id {env_id}")))?;
+            let max_episode_steps = get_optional_param(&json_, "max_episode_steps")
+                .map(as_int)
+                .transpose()?;
+            let auto_reset = get_optional_param(&json_, "auto_reset")
+                .and_then(Value::as_bool)
+                .unwrap_or(false);
+            let kwargs = get_optional_param(&json_, "kwargs")
+                .and_then(Value::as_object)
+                .cloned()
+                .unwrap_or_default();
+            let instance_id = envs.create(
+                env_id,
+                max_episode_steps.map(|x| x as usize),
+                auto_reset,
+                &kwargs,
+            )?;
+            json!({ "instance_id": instance_id }).into()
+        }
+        (Method::Get, []) => {
+            let all_envs: Map<String, Value> = envs
+                .envs
+                .iter()
+                .map(|(k, e)| (k.clone(), Value::from(e.env_id.clone())))
+                .collect();
+            json!({ "all_envs": all_envs }).into()
+        }
+        (Method::Get, [instance_id]) => {
+            json!({ "id": envs.lookup_env(instance_id)?.env_id }).into()
+        }
+        (Method::Delete, [instance_id]) => {
+            envs.lookup_env(instance_id)?;
+            envs.envs.remove(*instance_id);
+            return Ok(None);
+        }
+        (Method::Post, [instance_id, "reset"]) => {
+            let json_ = body?;
+            let seed = get_optional_param(&json_, "seed").map(as_int).transpose()?;
+            // NOTE: Accepted for compatibility, none of the native environments takes options.
+            if let Some(options) = get_optional_param(&json_, "options").filter(|o| !o.is_object())
+            {
+                return Err(InvalidUsage::new(format!("Invalid options {options}")));
+            }
+            let rr = envs.lookup_env(instance_id)?.reset(seed)?;
+            Body::object([("observation", rr.observation), ("info", rr.info.into())])
+        }
+        (Method::Get, [instance_id, "render"]) => {
+            Body::object([("render_frame", envs.lookup_env(instance_id)?.env.render())])
+        }
+        (Method::Post, [instance_id, "step"]) => {
+            let json_ = body?;
+            let action = get_required_param(&json_, "action")?;
+            let sr = envs.lookup_env(instance_id)?.step(action)?;
+            Body::object([
+                ("observation", sr.observation),
+                ("reward", json!(sr.reward).into()),
+                ("terminated", json!(sr.terminated).into()),
+                ("truncated", json!(sr.truncated).into()),
+                ("info", sr.info),
+            ])
+        }
+        (Method::Get, [instance_id, "action_space"]) => {
+            json!({ "info": envs.lookup_env(instance_id)?.env.action_space_info()? }).into()
+        }
+        (Method::Get, [instance_id, "action_space", "sample"]) => {
+            json!({ "action": envs.lookup_env(instance_id)?.env.action_space_sample()? }).into()
+        }
+        (Method::Get, [instance_id, "action_space", "contains", action]) => {
+            let action = serde_json::from_str::<Value>(action)
+                .map_err(|_| InvalidUsage::new(format!("Malformed action '{action}'")))?;
+            let member = envs
+                .lookup_env(instance_id)?
+                .env
+                .action_space_contains(&action);
+            json!({ "member": member }).into()
+        }
+        (Method::Get, [instance_id, "observation_space"]) => {
+            json!({ "info": envs.lookup_env(instance_id)?.env.observation_space_info()? }).into()
+        }
+        (Method::Post, [instance_id, "observation_space", "contains"]) => {
+            let json_ = body?;
+            let j = json_
+                .as_object()
+                .ok_or_else(|| InvalidUsage::new("Request is not a valid json".to_string()))?;
+            let member = envs.get_observation_space_contains(instance_id, j)?;
+            json!({ "member": member }).into()
+        }
+        (Method::Get, [instance_id, "transitions"]) => {
+            let transitions = envs
+                .lookup_env(instance_id)?
+                .env
+                .transitions()
+                .ok_or_else(|| no_transition_model(instance_id))?;
+            json!({ "transitions": transitions }).into()
+        }
+        (Method::Post, [instance_id, "episodes"]) => {
+            let json_ = body?;
+            let count = as_int(get_required_param(&json_, "count")?)?;
+            let seed = get_optional_param(&json_, "seed").map(as_int).transpose()?;
+            let episodes = envs.get_episode_samples(instance_id, seed, count as usize)?;
+            Body::object([("episodes", episodes)])
+        }
+        _ => return Err(not_found()),
+    };
+
+    Ok(Some(ret))
+}
+
+fn read_json(req: &mut Request) -> Result<Value, InvalidUsage> {
+    let mut body = String::new();
+    req.as_reader()
+        .read_to_string(&mut body)
+        .ok()
+        .and_then(|_| serde_json::from_str(&body).ok())
+        .ok_or_else(|| InvalidUsage::new("Request is not a valid json".to_string()))
+}
+
+/// Same as gym_http_server.py, None, "" and [] are missing values.
+fn get_optional_param<'a>(json_: &'a Value, param: &str) -> Option<&'a Value> {
+    json_.get(param).filter(|v| {
+        !(v.is_null() || v.as_str() == Some("") || v.as_array().is_some_and(Vec::is_empty))
+    })
+}
+
+fn get_required_param<'a>(json_: &'a Value, param: &str) -> Result<&'a Value, InvalidUsage> {
+    get_optional_param(json_, param).ok_or_else(|| {
+        InvalidUsage::new(format!(
+            "A required request parameter '{param}' was not provided"
+        ))
+    })
+}
+
+/// Same as int() in Python, seeds and counts are sent as strings by the client.
+fn as_int(val: &Value) -> Result<u64, InvalidUsage> {
+    val.as_u64()
+        .or_else(|| val.as_str().and_then(|s| s.trim().parse().ok()))
+        .ok_or_else(|| InvalidUsage::new(format!("Invalid integer {val}")))
+}
+
+/// 501, the client reports it as unsupported rather than as a failed request.
+fn no_transition_model(instance_id: &str) -> InvalidUsage {
+    InvalidUsage {
+        message: format!("Environment {instance_id} has no transition model"),
+        status_code: 501,
+    }
+}
+
+fn not_found() -> InvalidUsage {
+    InvalidUsage {
+        message: "Not Found".to_string(),
+        status_code: 404,
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|h| std::str::from_utf8(h).ok())
+            .and_then(|h| u8::from_str_radix(h, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(b)) => {
+                out.push(b);
+                i += 3;
+            }
+            (b, _) => {
+                out.push(b);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&out).into_owned()
+}
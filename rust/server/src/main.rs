@@ -0,0 +1,34 @@
+extern crate gym_http_server;
+
+use gym_http_server::Server;
+use std::env;
+use std::process::exit;
+
+const USAGE: &str = "usage: gym_http_server [-l LISTEN] [-p PORT]";
+
+fn main() {
+    let mut listen = "127.0.0.1".to_string();
+    let mut port = 40004u16;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let value = args.next();
+        match (arg.as_str(), value) {
+            ("-l" | "--listen", Some(v)) => listen = v,
+            ("-p" | "--port", Some(v)) => port = v.parse().unwrap_or_else(|_| exit_usage()),
+            _ => exit_usage(),
+        }
+    }
+
+    let server = Server::http((listen.as_str(), port)).unwrap_or_else(|e| {
+        eprintln!("Unable to start the server: {e}");
+        exit(1)
+    });
+    println!("Server starting at:  http://{}.", server.server_addr());
+    server.run();
+}
+
+fn exit_usage() -> ! {
+    eprintln!("{USAGE}");
+    exit(2)
+}
@@ -0,0 +1,161 @@
+use crate::InvalidUsage;
+use gymnasium::common::{defs::*, wire_format::*};
+use gymnasium::*;
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Spaces as described to the client, i.e. the inverse of Space::new and Space::observation.
+pub trait WireSpace: Space {
+    /// Same as _get_space_properties of gym_http_server.py.
+    fn info(&self) -> Result<Value, InvalidUsage>;
+
+    /// Same as _observation_to_jsonable of gym_http_server.py.
+    fn observation_to_body(&self, obs: &Self::Item) -> Result<Body, InvalidUsage>;
+}
+
+impl WireSpace for DiscreteSpace {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        Ok(json!({ "name": "Discrete", "n": self.n }))
+    }
+
+    fn observation_to_body(&self, obs: &Discrete) -> Result<Body, InvalidUsage> {
+        Ok(array_to_body("int64", &[1], obs.to_le_bytes().to_vec()))
+    }
+}
+
+impl WireSpace for BoxSpace<Continous> {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        Ok(json!({
+            "name": "Box",
+            "shape": self.shape,
+            "low": normalize_infs(&self.low),
+            "high": normalize_infs(&self.high),
+        }))
+    }
+
+    /// NOTE: Sent as float32, the dtype of the Box spaces of the native environments in gymnasium, same as
+    /// gym_http_server.py. These already round their observations to float32, i.e. nothing is lost.
+    fn observation_to_body(&self, obs: &Vec<Continous>) -> Result<Body, InvalidUsage> {
+        let bytes: Vec<u8> = obs.iter().flat_map(|&x| (x as f32).to_le_bytes()).collect();
+        Ok(array_to_body("float32", &self.shape, bytes))
+    }
+}
+
+impl WireSpace for BoxSpace<Discrete> {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        Ok(json!({ "name": "Box", "shape": self.shape, "low": self.low, "high": self.high }))
+    }
+
+    fn observation_to_body(&self, obs: &Vec<Discrete>) -> Result<Body, InvalidUsage> {
+        let bytes: Vec<u8> = obs.iter().flat_map(|x| x.to_le_bytes()).collect();
+        Ok(array_to_body("int64", &self.shape, bytes))
+    }
+}
+
+impl WireSpace for TupleSpace {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        let spaces = self
+            .spaces
+            .iter()
+            .map(WireSpace::info)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(json!({ "name": "Tuple", "spaces": spaces }))
+    }
+
+    fn observation_to_body(&self, obs: &Vec<AnyItem>) -> Result<Body, InvalidUsage> {
+        let items = self
+            .spaces
+            .iter()
+            .zip(obs)
+            .map(|(s, x)| s.observation_to_body(x))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Body::object([
+            ("type", json!("tuple").into()),
+            ("items", Body::array(items)),
+        ]))
+    }
+}
+
+impl WireSpace for DictSpace {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        let spaces = self
+            .spaces
+            .iter()
+            .map(|(k, s)| Ok((k.clone(), s.info()?)))
+            .collect::<Result<Map<_, _>, InvalidUsage>>()?;
+        Ok(json!({ "name": "Dict", "spaces": spaces }))
+    }
+
+    fn observation_to_body(&self, obs: &BTreeMap<String, AnyItem>) -> Result<Body, InvalidUsage> {
+        let items = self
+            .spaces
+            .iter()
+            .filter_map(|(k, s)| Some((k, s, obs.get(k)?)))
+            .map(|(k, s, x)| Ok((k.clone(), s.observation_to_body(x)?)))
+            .collect::<Result<Vec<_>, InvalidUsage>>()?;
+        Ok(Body::object([
+            ("type", json!("dict").into()),
+            ("items", Body::object(items)),
+        ]))
+    }
+}
+
+/// NOTE: Only the components of the spaces the native environments use are supported, the others are
+/// reported as 501 rather than aborting the server.
+impl WireSpace for AnySpace {
+    fn info(&self) -> Result<Value, InvalidUsage> {
+        match self {
+            AnySpace::Discrete(s) => s.info(),
+            AnySpace::Box(s) => s.info(),
+            AnySpace::Tuple(s) => s.info(),
+            AnySpace::Dict(s) => s.info(),
+            AnySpace::Other { info, .. } => Ok(info.clone()),
+            _ => Err(not_served(format!("{} spaces are not served", self.name()))),
+        }
+    }
+
+    fn observation_to_body(&self, obs: &AnyItem) -> Result<Body, InvalidUsage> {
+        match (self, obs) {
+            (AnySpace::Discrete(s), AnyItem::Discrete(x)) => s.observation_to_body(x),
+            (AnySpace::Box(s), AnyItem::BoxContinous(x)) => s.observation_to_body(x),
+            (AnySpace::Tuple(s), AnyItem::Tuple(x)) => s.observation_to_body(x),
+            (AnySpace::Dict(s), AnyItem::Dict(x)) => s.observation_to_body(x),
+            _ => Err(not_served(format!(
+                "{obs:?} items of {} spaces are not served",
+                self.name()
+            ))),
+        }
+    }
+}
+
+pub(crate) fn not_served(message: String) -> InvalidUsage {
+    InvalidUsage {
+        message,
+        status_code: 501,
+    }
+}
+
+/// Action as sent by the client, i.e. the inverse of Space::action.
+pub fn action_to_value<S: Space>(space: &S, action: &S::Item) -> Value {
+    space
+        .action_request(action)
+        .remove("action")
+        .unwrap_or_default()
+}
+
+/// NOTE: The data is kept as raw bytes, until encoded for the wire. Refer: WireFormat::encode.
+fn array_to_body(ty: &str, shape: &[usize], bytes: Vec<u8>) -> Body {
+    Body::object([
+        ("type", json!(ty).into()),
+        ("shape", json!(shape).into()),
+        ("data", Body::binary(bytes)),
+    ])
+}
+
+/// Infinity is not valid JSON, bounds are sent as the extreme finite values instead, same as _replace_inf of
+/// gym_http_server.py. The client maps them back, refer: BoxSpaceElement::from_bound.
+fn normalize_infs(xs: &[Continous]) -> Vec<Continous> {
+    xs.iter()
+        .map(|&x| x.clamp(Continous::MIN, Continous::MAX))
+        .collect()
+}
@@ -0,0 +1,203 @@
+extern crate float_eq;
+extern crate gym_http_server;
+extern crate gymnasium;
+extern crate serde_json;
+
+use float_eq::*;
+use gym_http_server::Server;
+use gymnasium::common::{defs::*, error::*};
+use gymnasium::*;
+use serde_json::{json, to_value};
+use std::thread;
+
+fn start_server() -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", server.server_addr());
+    thread::spawn(move || server.run());
+
+    url
+}
+
+#[test]
+fn frozen_lake_e2e() {
+    let url = start_server();
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        &url,
+        "FrozenLake-v1",
+        Some(1),
+        Some(false),
+        Some(true),
+        &[
+            ("render_mode", to_value("ansi").unwrap()),
+            ("is_slippery", to_value(false).unwrap()),
+            ("desc", to_value(["GGGH", "GSGH", "GGGF", "FFFG"]).unwrap()),
+        ],
+    )
+    .unwrap()
+    .close_on_drop();
+    assert_eq!(env.name().unwrap(), "FrozenLake-v1");
+    assert_eq!(env.observation_space().n, 16);
+    assert_eq!(env.action_space().n, 4);
+    assert_eq!(transitions(&env).unwrap().len(), 64);
+    assert!(Environment::<DiscreteSpace, DiscreteSpace>::envs(&url)
+        .unwrap()
+        .contains_key(env.instance_id()));
+
+    let options = json!({ "start": 0 });
+    let ri = env.reset(Some(2718), options.as_object()).unwrap();
+    assert_eq!(ri.observation, 5);
+    assert_eq!(ri.info, json!({ "prob": 1 }));
+
+    let rf = env.render().unwrap();
+    assert_eq!(
+        rf.as_str().unwrap(),
+        "\nGGGH\nG\u{1b}[41mS\u{1b}[0mGH\nGGGF\nFFFG\n"
+    );
+
+    let si = env.step(&1).unwrap();
+    assert_eq!(si.observation, 9);
+    assert!(si.terminated);
+    assert!(si.truncated);
+    assert_float_eq!(si.reward, 1., rmax <= 1e-16);
+
+    let rf = env.render().unwrap();
+    assert_eq!(
+        rf.as_str().unwrap(),
+        "  (Down)\nGGGH\nGSGH\nG\u{1b}[41mG\u{1b}[0mGF\nFFFG\n"
+    );
+
+    assert!(env.action_space_contains(&3).unwrap());
+    assert!(!env.action_space_contains(&4).unwrap());
+    assert!(env
+        .observation_space_matches(&[("n", to_value(16).unwrap())])
+        .unwrap());
+    assert!(env
+        .action_space()
+        .contains(&env.action_space_sample().unwrap()));
+}
+
+#[test]
+fn frozen_lake_auto_reset_e2e() {
+    let url = start_server();
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        &url,
+        "FrozenLake-v1",
+        Some(1),
+        Some(true),
+        None,
+        &[("is_slippery", to_value(false).unwrap())],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    env.reset(Some(0), None).unwrap();
+    let si = env.step(&2).unwrap();
+    assert_eq!(si.observation, 0);
+    assert!(si.truncated);
+    assert_eq!(si.info["prob"], 1);
+    assert_eq!(si.info["final_info"], json!({ "prob": 1. }));
+    assert_eq!(si.info["final_observation"]["type"], "int64");
+}
+
+#[test]
+fn frozen_lake_slippery_transitions_e2e() {
+    let url = start_server();
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        &url,
+        "FrozenLake-v1",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap()
+    .close_on_drop();
+
+    let p = transitions(&env).unwrap();
+    let ts = &p[&(0, 2)];
+    assert_eq!(ts.len(), 3);
+    assert_eq!(
+        ts.iter().map(|t| t.next_state).collect::<Vec<_>>(),
+        [4, 1, 0]
+    );
+    assert_float_eq!(ts[0].probability, 1. / 3., rmax <= 1e-16);
+    assert!(p[&(5, 0)][0].done);
+
+    let model = transition_model(&env).unwrap();
+    assert_eq!(model.p().dim(), (16, 4, 16));
+    assert_float_eq!(model.p()[[0, 2, 4]], 1. / 3., rmax <= 1e-16);
+    assert_float_eq!(model.r()[[14, 2]], 1. / 3., rmax <= 1e-15);
+}
+
+#[test]
+fn no_transition_model_e2e() {
+    let url = start_server();
+    let env = Environment::<AnySpace, AnySpace>::new(&url, "CartPole-v1", None, None, None, &[])
+        .unwrap()
+        .close_on_drop();
+    assert!(matches!(
+        transition_model(&env),
+        Err(GymError::Unsupported(_))
+    ));
+
+    let env = Environment::<AnySpace, AnySpace>::new(&url, "FrozenLake-v1", None, None, None, &[])
+        .unwrap()
+        .close_on_drop();
+    assert_eq!(transition_model(&env).unwrap().n_s(), 16);
+}
+
+#[test]
+fn invalid_usage_e2e() {
+    let url = start_server();
+    let err = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        &url,
+        "NoSuchEnv-v0",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap_err();
+    assert!(matches!(err, GymError::Http { status: 400, .. }));
+
+    // The server keeps serving after an invalid map.
+    for desc in [
+        json!([]),
+        json!(["SF", "F"]),
+        json!(["FF", "FG"]),
+        json!(["SX"]),
+    ] {
+        let err = Environment::<DiscreteSpace, DiscreteSpace>::new(
+            &url,
+            "FrozenLake-v1",
+            None,
+            None,
+            None,
+            &[("desc", desc)],
+        )
+        .unwrap_err();
+        assert!(
+            matches!(err, GymError::Http { status: 400, message } if message.contains("FrozenLake map"))
+        );
+    }
+
+    let env = Environment::<DiscreteSpace, DiscreteSpace>::new(
+        &url,
+        "FrozenLake-v1",
+        None,
+        None,
+        None,
+        &[],
+    )
+    .unwrap();
+    let err = env.step(&0).unwrap_err();
+    assert!(
+        matches!(err, GymError::Http { status: 400, message } if message.contains("before calling env.reset()"))
+    );
+
+    env.close().unwrap();
+    assert!(matches!(
+        env.name().unwrap_err(),
+        GymError::Http { status: 400, .. }
+    ));
+}
@@ -0,0 +1,100 @@
+extern crate gym_http_server;
+extern crate gymnasium;
+extern crate serde_json;
+
+use gym_http_server::{wire::WireSpace, Server};
+use gymnasium::common::{defs::*, wire_format::*};
+use gymnasium::*;
+use serde_json::{json, Value};
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::thread;
+
+fn start_server() -> String {
+    let server = Server::http("127.0.0.1:0").unwrap();
+    let addr = server.server_addr().to_string();
+    thread::spawn(move || server.run());
+
+    addr
+}
+
+/// Content type and body of the response, requesting with the given Accept header.
+fn request(addr: &str, path: &str, accept: &str, body: &Value) -> (String, Vec<u8>) {
+    let body = body.to_string();
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "POST {path} HTTP/1.1\r\nHost: {addr}\r\nAccept: {accept}\r\nContent-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )
+    .unwrap();
+    let mut res = vec![];
+    stream.read_to_end(&mut res).unwrap();
+
+    let split = res.windows(4).position(|w| w == b"\r\n\r\n").unwrap();
+    let head = String::from_utf8_lossy(&res[..split]).to_lowercase();
+    let content_type = head
+        .lines()
+        .find_map(|l| l.strip_prefix("content-type: "))
+        .unwrap()
+        .to_string();
+
+    (content_type, res[split + 4..].to_vec())
+}
+
+#[test]
+fn wire_format_e2e() {
+    let addr = start_server();
+    let (_, body) = request(
+        &addr,
+        "/v1/envs/",
+        "application/json",
+        &json!({ "env_id": "CartPole-v1" }),
+    );
+    let res = WireFormat::Json.decode(&body).unwrap().value;
+    let path = format!("/v1/envs/{}/reset/", res["instance_id"].as_str().unwrap());
+
+    let (content_type, body) = request(&addr, &path, "application/json", &json!({ "seed": 42 }));
+    assert_eq!(content_type, "application/json");
+    let json = WireFormat::Json.decode(&body).unwrap();
+    assert!(json.value["observation"]["data"].is_string());
+    assert_eq!(json.value["observation"]["type"], "float32");
+
+    let (content_type, body) = request(&addr, &path, WireFormat::ACCEPT, &json!({ "seed": 42 }));
+    assert_eq!(content_type, "application/msgpack");
+    let msgpack = WireFormat::MessagePack.decode(&body).unwrap();
+    assert_eq!(
+        msgpack.binaries.keys().collect::<Vec<_>>(),
+        ["/observation/data"]
+    );
+
+    let space = BoxSpace::<Continous> {
+        shape: vec![4],
+        high: vec![f64::INFINITY; 4],
+        low: vec![f64::NEG_INFINITY; 4],
+    };
+    assert_eq!(
+        space.observation(&json.get("observation")).unwrap(),
+        space.observation(&msgpack.get("observation")).unwrap()
+    );
+}
+
+#[test]
+fn unserved_spaces() {
+    let space = AnySpace::MultiBinary(MultiBinarySpace { shape: vec![3] });
+    assert_eq!(space.info().unwrap_err().status_code, 501);
+
+    let space = AnySpace::Discrete(DiscreteSpace { n: 2 });
+    let err = space
+        .observation_to_body(&AnyItem::BoxDiscrete(vec![1]))
+        .unwrap_err();
+    assert_eq!(err.status_code, 501);
+    assert_eq!(
+        space
+            .observation_to_body(&AnyItem::Discrete(1))
+            .unwrap()
+            .value["type"],
+        "int64"
+    );
+}
@@ -1,8 +1,9 @@
 import logging
 import base64
 import zlib
-import struct
+import msgpack
 import numpy as np
+import requests
 
 import gym_http_client
 
@@ -87,6 +88,15 @@ def test_action_space_contains():
     assert client.env_action_space_contains(instance_id, 2) is False
 
 
+@with_server
+def test_action_space_contains_box():
+    client = gym_http_client.Client(get_remote_base())
+    instance_id = client.env_create("MountainCarContinuous-v0")
+    assert client.env_action_space_contains(instance_id, [0.5]) is True
+    assert client.env_action_space_contains(instance_id, [1.5]) is False
+    assert client.env_action_space_contains(instance_id, [0.5, 0.5]) is False
+
+
 @with_server
 def test_observation_space_box():
     client = gym_http_client.Client(get_remote_base())
@@ -111,10 +121,8 @@ def test_observation_space_contains():
 
 
 def deserialize_from_bytes(ty, s):
-    arr = bytearray(b64_decode_decompress(s))
-    fmt = f"{int(len(arr) / np.dtype(ty).itemsize)}d"
-    arr = struct.unpack(fmt, arr)
-    return arr
+    arr = np.frombuffer(b64_decode_decompress(s), dtype=ty)
+    return arr.tolist()
 
 
 @with_server
@@ -123,8 +131,10 @@ def test_reset():
 
     instance_id = client.env_create("CartPole-v1")
     init_obs = client.env_reset(instance_id)
+    assert init_obs["type"] == "float32"
     init_obs = deserialize_from_bytes(init_obs["type"], init_obs["data"])
     assert len(init_obs) == 4
+    assert all(-0.05 <= x <= 0.05 for x in init_obs)
 
     instance_id = client.env_create("FrozenLake-v1")
     init_obs = client.env_reset(instance_id)
@@ -141,6 +151,7 @@ def test_step():
     client.env_reset(instance_id)
     action = client.env_action_space_sample(instance_id)
     observation, reward, terminated, truncated, info = client.env_step(instance_id, action)
+    assert observation["type"] == "float32"
     observation = deserialize_from_bytes(observation["type"], observation["data"])
     assert len(observation) == 4
     assert isinstance(reward, float)
@@ -160,6 +171,20 @@ def test_step():
     assert isinstance(observation[0], float)
 
 
+@with_server
+def test_step_tuple():
+    client = gym_http_client.Client(get_remote_base())
+    instance_id = client.env_create("Blackjack-v1")
+    obs_info = client.env_observation_space_info(instance_id)
+    assert obs_info["name"] == "Tuple"
+    assert [x["name"] for x in obs_info["spaces"]] == ["Discrete", "Discrete", "Discrete"]
+    init_obs = client.env_reset(instance_id)
+    assert init_obs["type"] == "tuple"
+    assert len(init_obs["items"]) == 3
+    observation, _, _, _, _ = client.env_step(instance_id, 0)
+    assert [x["shape"] for x in observation["items"]] == [[1], [1], [1]]
+
+
 @with_server
 def test_render_ansi():
     client = gym_http_client.Client(get_remote_base())
@@ -180,6 +205,42 @@ def test_render_rgb():
     assert len(b64_decode_decompress(rf["data"])) == 960000
 
 
+def post_reset(instance_id, accept):
+    return requests.post(
+        f"{get_remote_base()}/v1/envs/{instance_id}/reset/",
+        json={"seed": 42},
+        headers={"Accept": accept},
+        timeout=10,
+    )
+
+
+@with_server
+def test_reset_msgpack():
+    client = gym_http_client.Client(get_remote_base())
+    instance_id = client.env_create("CartPole-v1")
+    resp = post_reset(instance_id, "application/msgpack, application/json;q=0.9")
+    assert resp.headers["Content-Type"] == "application/msgpack"
+    body = msgpack.unpackb(resp.content)
+    obs = body["observation"]
+    # Raw bytes, i.e. neither base64 encoded nor compressed.
+    assert isinstance(obs["data"], bytes)
+    assert obs["shape"] == [4]
+    assert len(np.frombuffer(obs["data"], dtype=obs["type"])) == 4
+    assert isinstance(body["info"], dict)
+
+
+@with_server
+def test_reset_json_fallback():
+    client = gym_http_client.Client(get_remote_base())
+    instance_id = client.env_create("CartPole-v1")
+    for accept in ["application/json", "*/*"]:
+        resp = post_reset(instance_id, accept)
+        assert resp.headers["Content-Type"] == "application/json"
+        obs = resp.json()["observation"]
+        assert isinstance(obs["data"], str)
+        assert len(np.frombuffer(b64_decode_decompress(obs["data"]), dtype=obs["type"])) == 4
+
+
 @with_server
 def test_get_transitions():
     client = gym_http_client.Client(get_remote_base())